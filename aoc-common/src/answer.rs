@@ -0,0 +1,190 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The answer to a part of a puzzle.
+///
+/// Most answers are numbers, but some are words, and some are pictures that
+/// spell out letters. Numbers are kept as `Integer` whenever they fit in one,
+/// and compare by value whichever variant holds them.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    /// A number too large for `Integer`
+    BigInteger(i128),
+    /// A single line of text
+    Text(String),
+    /// Several lines of text, usually a picture drawn with `#` and `.`
+    Block(String),
+}
+
+impl Answer {
+    /// Reads an answer back from how it is displayed
+    pub fn parse(text: &str) -> Answer {
+        if let Ok(n) = text.parse::<i128>() {
+            Answer::from(n)
+        } else {
+            Answer::from(text.to_string())
+        }
+    }
+
+    /// The answer as a number, if it is one
+    pub fn as_integer(&self) -> Option<i128> {
+        match *self {
+            Answer::Integer(n) => Some(n.into()),
+            Answer::BigInteger(n) => Some(n),
+            Answer::Text(_) | Answer::Block(_) => None,
+        }
+    }
+
+    /// Whether the answer takes up more than one line
+    pub fn is_block(&self) -> bool {
+        matches!(self, Answer::Block(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(text) | Answer::Block(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Integer(n.into())
+                }
+            }
+        )*
+    };
+}
+
+/// For the types that don't always fit in an `i64`, but always fit in an `i128`
+macro_rules! impl_from_wide_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    i64::try_from(n).map_or(Answer::BigInteger(n as i128), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, i8, i16, i32, i64);
+impl_from_wide_integer!(u64, usize, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::from(n),
+            // Too large even for `BigInteger`
+            Err(_) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    /// Text with a line break in it becomes a `Block`
+    fn from(text: String) -> Answer {
+        if text.contains('\n') {
+            Answer::Block(text)
+        } else {
+            Answer::Text(text)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::from(text.to_string())
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialOrd for Answer {
+    /// Numbers are ordered by value and text alphabetically,
+    /// but a number and text can't be compared.
+    /// A `Block` compares like a `Text` with the same content, since a
+    /// one-line picture is read back as text.
+    fn partial_cmp(&self, other: &Answer) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Text(a) | Answer::Block(a), Answer::Text(b) | Answer::Block(b)) => {
+                Some(a.cmp(b))
+            }
+            _ => Some(self.as_integer()?.cmp(&other.as_integer()?)),
+        }
+    }
+}
+
+/// Compares the answer as it is displayed, which is how answers are recorded
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Text(text) | Answer::Block(text) => text == other,
+            _ => self.to_string().as_str() == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::from(5u128), Answer::Integer(5));
+        assert_eq!(Answer::BigInteger(5), Answer::Integer(5));
+        assert!(Answer::from(u64::MAX) > Answer::from(-3));
+        assert!(matches!(Answer::from(u64::MAX), Answer::BigInteger(_)));
+        assert_eq!(Answer::from(7u16), "7");
+        assert_eq!(Answer::Integer(7).partial_cmp(&Answer::from("7")), None);
+    }
+
+    #[test]
+    fn text_and_blocks_compare_by_content() {
+        let one_row = Answer::Block("#..#.".to_string());
+        assert_eq!(Answer::parse(&one_row.to_string()), one_row);
+        assert_eq!(Answer::Text("#.\n.#".to_string()), Answer::from("#.\n.#"));
+        assert_ne!(
+            Answer::Text("#.".to_string()),
+            Answer::Block("#.\n.#".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_reads_back_what_was_displayed() {
+        for answer in [
+            Answer::Integer(-12),
+            Answer::BigInteger(26_984_457_539_000_000_000_000),
+            Answer::Text("CEJKLUGJ".to_string()),
+            Answer::Block("#.#\n.#.".to_string()),
+        ] {
+            let parsed = Answer::parse(&answer.to_string());
+            assert_eq!(parsed, answer);
+            assert_eq!(
+                std::mem::discriminant(&parsed),
+                std::mem::discriminant(&answer)
+            );
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use crate::parse::{self, ParseError};
+use crate::point::{Point, Vec2, ALL_AROUND, ORTHOGONAL};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+///
+/// Cells are addressed by `(row, column)`, starting at the top-left corner,
+/// or by a [`Point`] whose `x` is the column and `y` the row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells listed row by row.
+    ///
+    /// Panics if the number of cells doesn't match the dimensions.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "cells don't fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for column in 0..width {
+                cells.push(f(row, column));
+            }
+        }
+        Grid::from_cells(width, height, cells)
+    }
+
+    /// Parses a block of text where every character is one cell.
+    ///
+    /// `f` returns `None` for characters that aren't allowed in the grid.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in parse::lines(input) {
+            let before = cells.len();
+            for (column, c) in line.chars() {
+                let cell = f(c).ok_or_else(|| {
+                    line.error_at(column, format!("unexpected character `{}`", c))
+                })?;
+                cells.push(cell);
+            }
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error_at_end(format!(
+                        "expected {} cells like the first row, found {}",
+                        width, row_width
+                    )));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid::from_cells(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, column): (usize, usize)) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[self.index_of(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        point
+            .to_grid_index()
+            .is_some_and(|position| self.contains(position))
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_grid_index()?)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.to_grid_index()?)
+    }
+
+    /// Moves from `position` by the given offset, if the result is still on the grid
+    pub fn offset(&self, position: (usize, usize), offset: Vec2) -> Option<(usize, usize)> {
+        let position = (Point::from_grid_index(position) + offset).to_grid_index()?;
+        if self.contains(position) {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    /// Positions reached by applying each offset to `position`, skipping those off the grid
+    pub fn neighbors<'a>(
+        &'a self,
+        position: (usize, usize),
+        offsets: &'a [Vec2],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// The cells above, below, left and right of `position`
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &ORTHOGONAL)
+    }
+
+    /// All eight cells surrounding `position`, including the diagonal ones
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &ALL_AROUND)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.positions().map(Point::from_grid_index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` doesn't accept a zero width
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.column(column))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, (row, column): (usize, usize)) -> usize {
+        row * self.width + column
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::from_cells(width, height, vec![value; width * height])
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a map of single digits, such as a heightmap
+    pub fn parse_digits(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_with(input, |c| c.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside the grid",
+            position
+        );
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        assert!(
+            self.contains(position),
+            "{:?} is outside the grid",
+            position
+        );
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+/// Renders the grid one row per line, without separators between cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_point_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_digits_reads_rows_of_digits() {
+        let grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid[Point::new(2, 0)], 3);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_with_points_at_the_bad_character_or_row() {
+        let error = Grid::parse_digits("123\n4x6\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert_eq!(error.message(), "unexpected character `x`");
+
+        let error = Grid::parse_digits("123\n45\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+        assert_eq!(
+            error.message(),
+            "expected 3 cells like the first row, found 2"
+        );
+
+        let grid = Grid::parse_with("#.\n.#", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(grid.iter().filter(|&&on| on).count(), 2);
+        assert!(Grid::parse_chars("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn neighbors_stay_on_the_grid() {
+        let grid = Grid::filled(3, 3, 0);
+        let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(corner, [(1, 0), (0, 1)]);
+        let edge: Vec<_> = grid.neighbors4((1, 2)).collect();
+        assert_eq!(edge, [(0, 2), (2, 2), (1, 1)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+
+        let corner: Vec<_> = grid.neighbors8((2, 2)).collect();
+        assert_eq!(corner, [(1, 1), (1, 2), (2, 1)]);
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |row, column| row * 10 + column);
+        let rows: Vec<&[usize]> = grid.rows().collect();
+        assert_eq!(rows, [[0, 1, 2], [10, 11, 12]]);
+        let columns: Vec<Vec<usize>> = grid
+            .columns()
+            .map(|column| column.copied().collect())
+            .collect();
+        assert_eq!(columns, [[0, 10], [1, 11], [2, 12]]);
+        assert_eq!(grid.positions().last(), Some((1, 2)));
+    }
+
+    #[test]
+    fn nothing_outside_the_grid() {
+        let mut grid = Grid::filled(2, 3, 'a');
+        assert_eq!(grid.get((2, 1)), Some(&'a'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get_mut((0, 2)), None);
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+        assert!(!grid.contains_point(Point::new(0, -1)));
+        assert_eq!(grid.offset((0, 0), Point::new(-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), Point::new(1, 2)), Some((2, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 2) is outside the grid")]
+    fn indexing_outside_the_grid_panics() {
+        let grid = Grid::filled(2, 3, 0);
+        let _ = grid[(0, 2)];
+    }
+}
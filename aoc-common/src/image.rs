@@ -0,0 +1,191 @@
+use crate::grid::Grid;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// A colour, with 8 bits for each of red, green and blue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 30, 30);
+
+    /// The colour `t` of the way from `self` to `other`, where `t` is from 0 to 1
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    /// The `index`th of a sequence of colours that are easy to tell apart,
+    /// for when neighboring regions need different colours
+    pub fn distinct(index: usize) -> Rgb {
+        // Stepping around the colour wheel by the golden angle never lands close to an earlier hue
+        let hue = (index as f64 * 137.507_764) % 360.0;
+        Rgb::from_hue(hue)
+    }
+
+    /// A fully saturated, slightly darkened colour with the given hue in degrees
+    fn from_hue(hue: f64) -> Rgb {
+        let value = 0.9;
+        let x = value * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 / 60 {
+            0 => (value, x, 0.0),
+            1 => (x, value, 0.0),
+            2 => (0.0, value, x),
+            3 => (0.0, x, value),
+            4 => (x, 0.0, value),
+            _ => (value, 0.0, x),
+        };
+        let channel = |c: f64| (c * 255.0).round() as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+}
+
+/// The file formats an [`Image`] can be written as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    /// The binary `P6` variant of the portable pixmap format
+    Ppm,
+}
+
+impl ImageFormat {
+    /// Picks the format from the file extension, PNG unless it is `.ppm`
+    pub fn from_path(path: &Path) -> ImageFormat {
+        match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("ppm") => ImageFormat::Ppm,
+            _ => ImageFormat::Png,
+        }
+    }
+}
+
+/// A picture of a grid, with a colour per cell.
+///
+/// Each cell becomes a square of `scale` by `scale` pixels when written,
+/// since most puzzle grids are too small to make out otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    cells: Grid<Rgb>,
+    scale: usize,
+}
+
+impl Image {
+    pub fn new(cells: Grid<Rgb>) -> Image {
+        Image { cells, scale: 1 }
+    }
+
+    pub fn cells(&self) -> &Grid<Rgb> {
+        &self.cells
+    }
+
+    /// Draws each cell as a square of `scale` by `scale` pixels
+    pub fn with_scale(mut self, scale: usize) -> Image {
+        assert!(scale > 0, "images need a scale of at least 1");
+        self.scale = scale;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width() * self.scale
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height() * self.scale
+    }
+
+    /// The pixels row by row, three bytes for each
+    fn pixel_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.width() * self.height() * 3);
+        for row in self.cells.rows() {
+            let start = bytes.len();
+            for &Rgb(r, g, b) in row {
+                for _ in 0..self.scale {
+                    bytes.extend_from_slice(&[r, g, b]);
+                }
+            }
+            let end = bytes.len();
+            for _ in 1..self.scale {
+                bytes.extend_from_within(start..end);
+            }
+        }
+        bytes
+    }
+
+    pub fn write_ppm(&self, out: &mut impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        out.write_all(&self.pixel_bytes())
+    }
+
+    pub fn write_png(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width() as u32, self.height() as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixel_bytes())
+            .map_err(io::Error::other)
+    }
+
+    pub fn write(&self, out: &mut impl Write, format: ImageFormat) -> io::Result<()> {
+        match format {
+            ImageFormat::Png => self.write_png(out),
+            ImageFormat::Ppm => self.write_ppm(out),
+        }
+    }
+
+    /// Writes the image to `path`, in the format its extension asks for
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write(&mut out, ImageFormat::from_path(path))?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ppm_repeats_scaled_cells() {
+        let cells = Grid::from_cells(2, 1, vec![Rgb::BLACK, Rgb::WHITE]);
+        let mut out = Vec::new();
+        Image::new(cells).with_scale(2).write_ppm(&mut out).unwrap();
+
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]);
+        }
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn png_has_signature_and_size() {
+        let cells = Grid::filled(3, 2, Rgb::RED);
+        let mut out = Vec::new();
+        Image::new(cells).write_png(&mut out).unwrap();
+
+        assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+        // The IHDR chunk comes first, starting with the width and height
+        assert_eq!(&out[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(
+            ImageFormat::from_path(Path::new("day09.PPM")),
+            ImageFormat::Ppm
+        );
+        assert_eq!(
+            ImageFormat::from_path(Path::new("day09.png")),
+            ImageFormat::Png
+        );
+        assert_eq!(ImageFormat::from_path(Path::new("day09")), ImageFormat::Png);
+    }
+}
@@ -0,0 +1,135 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input for a run is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The `input.txt` checked in next to the day's crate
+    Default,
+    File(PathBuf),
+    /// One of the day's named inputs, e.g. `alice` for `day07/inputs/alice.txt`
+    Named(String),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` stands for stdin
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Reads the input and tidies it up with [`normalize`]
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        self.read_raw(day).map(|input| normalize(&input))
+    }
+
+    fn read_raw(&self, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::Default => read_file(&default_input_path(day)),
+            InputSource::File(path) => read_file(path),
+            InputSource::Named(name) => read_file(&named_input_path(day, name)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// The name of a named input, `None` for the other sources
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            InputSource::Named(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Undoes what editors on other systems do to a saved input, so every day can rely on
+/// lines ending in `\n`, blank lines being empty and the input ending in a single line break.
+///
+/// Strips a byte order mark, turns `\r\n` and lone `\r` line endings into `\n`,
+/// strips whitespace from the end of each line and drops blank lines at the end.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut result = String::with_capacity(input.len() + 1);
+    let lines = input.split('\n').flat_map(|line| {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        line.split('\r')
+    });
+    for line in lines {
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+    result.truncate(result.trim_end_matches('\n').len());
+    if !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+}
+
+/// The checked in input of the given day, e.g. `day07/input.txt`
+pub fn default_input_path(day: u8) -> PathBuf {
+    day_dir(day).join("input.txt")
+}
+
+/// Where the day's other inputs are kept, e.g. `day07/inputs`
+pub fn named_inputs_dir(day: u8) -> PathBuf {
+    day_dir(day).join("inputs")
+}
+
+/// The named input of the given day, e.g. `day07/inputs/alice.txt` for `alice`
+pub fn named_input_path(day: u8, name: &str) -> PathBuf {
+    named_inputs_dir(day).join(format!("{}.txt", name))
+}
+
+/// The names of the day's named inputs in alphabetical order,
+/// which are the `.txt` files in its `inputs` directory
+pub fn input_names(day: u8) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(named_inputs_dir(day)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_tidies_up_windows_inputs() {
+        assert_eq!(
+            normalize("\u{feff}1,2, 3 \r\n\t\r\n4\r5\r\n\r\n"),
+            "1,2, 3\n\n4\n5\n"
+        );
+        assert_eq!(normalize("start-A\nA-end"), "start-A\nA-end\n");
+        assert_eq!(normalize(" \r\n"), "");
+    }
+}
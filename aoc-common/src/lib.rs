@@ -0,0 +1,166 @@
+mod answer;
+pub mod grid;
+pub mod image;
+mod input;
+mod logging;
+pub mod parse;
+pub mod point;
+
+pub use answer::Answer;
+pub use grid::Grid;
+pub use image::{Image, ImageFormat, Rgb};
+pub use input::{
+    default_input_path, input_names, named_input_path, named_inputs_dir, normalize, InputSource,
+};
+pub use logging::init_logging;
+pub use parse::ParseError;
+pub use point::{Diagonal, Direction, Point, Vec2};
+
+use std::fmt;
+use std::process;
+
+/// One of the two halves of a puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The part has not been solved yet
+    Unimplemented,
+    Parse(ParseError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Unimplemented => write!(f, "unimplemented"),
+            SolveError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolveError::Unimplemented => None,
+            SolveError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+pub type SolveResult = Result<Answer, SolveError>;
+
+/// A single day of the calendar.
+///
+/// Parts that have not been solved yet keep the default implementation,
+/// which reports them as unimplemented.
+pub trait Solution: Sync {
+    /// The day of December this puzzle was released on
+    fn day(&self) -> u8;
+
+    /// Only parses the input, so it can be checked or timed without solving anything
+    fn parse(&self, input: &str) -> Result<(), ParseError>;
+
+    fn part1(&self, _input: &str) -> SolveResult {
+        Err(SolveError::Unimplemented)
+    }
+
+    fn part2(&self, _input: &str) -> SolveResult {
+        Err(SolveError::Unimplemented)
+    }
+
+    /// Draws the puzzle's grid, for days where a picture helps to see what is going on
+    fn render(&self, _input: &str) -> Result<Image, SolveError> {
+        Err(SolveError::Unimplemented)
+    }
+
+    fn solve(&self, part: Part, input: &str) -> SolveResult {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+/// Formats the result of a single part, the same way for every day.
+///
+/// Parse errors quote the offending line of `input`.
+pub fn format_result(part: Part, result: &SolveResult, input: &str) -> String {
+    match result {
+        Ok(answer) if answer.is_block() => format!("Part {}:\n{}", part, answer),
+        Ok(answer) => format!("Part {}: {}", part, answer),
+        Err(SolveError::Parse(e)) => format!("Part {}: {}", part, e.diagnostic(input)),
+        Err(e) => format!("Part {}: {}", part, e),
+    }
+}
+
+/// Entry point shared by the per-day binaries.
+///
+/// Accepts an optional `--input <path>` argument, where `-` reads from stdin.
+/// Without it, the day's checked in `input.txt` is used. Each `-v` logs more
+/// of what the solution is doing to stderr.
+pub fn run(solution: &dyn Solution) {
+    let mut source = InputSource::Default;
+    let mut verbosity = 0;
+    let mut args = std::env::args().skip(1);
+    let usage = || -> ! {
+        eprintln!("Usage: day{:02} [--input <path>] [-v]", solution.day());
+        process::exit(2);
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => match args.next() {
+                Some(path) => source = InputSource::from_arg(&path),
+                None => usage(),
+            },
+            "--verbose" => verbosity += 1,
+            "-v" | "-vv" | "-vvv" => verbosity += arg.len() as u8 - 1,
+            _ => usage(),
+        }
+    }
+    init_logging(verbosity);
+
+    let input = match source.read(solution.day()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut failed = false;
+    for part in Part::ALL {
+        let result = solution.solve(part, &input);
+        println!("{}", format_result(part, &result, &input));
+        failed |= matches!(result, Err(SolveError::Parse(_)));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,23 @@
+use std::io::{self, IsTerminal};
+use tracing_subscriber::EnvFilter;
+
+/// Sends the solutions' log events to stderr, where they can't mix with the answers.
+///
+/// When `RUST_LOG` is set it decides what is logged, e.g. `RUST_LOG=day13=debug`.
+/// Otherwise `verbosity` does: only warnings by default, debug events such as
+/// folds and paths at 1, and everything down to single packets at 2.
+pub fn init_logging(verbosity: u8) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+    // Only fails if logging was set up already, which is fine
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .try_init();
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Invalid puzzle input, pointing at where the problem was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    /// Both `line` and `column` start at 1
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error about the input as a whole, reported just past its last line
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(input.lines().count() + 1, 1, message)
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Describes the error along with the offending line of `input`,
+    /// marking the column the error was found at.
+    /// Long lines are cut down to the characters around that column.
+    pub fn diagnostic(&self, input: &str) -> String {
+        const CONTEXT: usize = 40;
+
+        let mut result = self.to_string();
+        if let Some(text) = self.line.checked_sub(1).and_then(|i| input.lines().nth(i)) {
+            let number = self.line.to_string();
+            let padding = " ".repeat(number.len());
+            let chars: Vec<char> = text.chars().collect();
+            let column = self.column.saturating_sub(1).min(chars.len());
+            let start = column.saturating_sub(CONTEXT);
+            let end = (column + CONTEXT).min(chars.len());
+            let mut shown: String = chars[start..end].iter().collect();
+            // Keep the marker aligned when the line contains tabs
+            let mut indent: String = chars[start..column]
+                .iter()
+                .map(|&c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            if start > 0 {
+                shown.insert_str(0, "...");
+                indent.insert_str(0, "   ");
+            }
+            if end < chars.len() {
+                shown.push_str("...");
+            }
+            result.push_str(&format!(
+                "\n{} |\n{} | {}\n{} | {}^",
+                padding, number, shown, padding, indent
+            ));
+        }
+        result
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid input at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of puzzle input that remembers where it came from,
+/// so errors about it can point at the right place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+/// Splits the input into lines, numbered from 1
+pub fn lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input.lines().enumerate().map(|(i, text)| InputLine {
+        number: i + 1,
+        text,
+    })
+}
+
+/// Splits the input into sections separated by blank lines, such as the boards of a bingo game.
+/// Several blank lines in a row separate sections just like one does.
+pub fn sections(input: &str) -> impl Iterator<Item = Vec<InputLine<'_>>> {
+    let mut lines = lines(input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(InputLine::is_empty).is_some() {}
+        let section: Vec<InputLine> =
+            std::iter::from_fn(|| lines.next_if(|line| !line.is_empty())).collect();
+        (!section.is_empty()).then_some(section)
+    })
+}
+
+impl<'a> InputLine<'a> {
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The column `token` starts at, where `token` is a slice of this line.
+    /// Anything else is reported at the end of the line.
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        let offset = if self.text.is_char_boundary(offset) {
+            offset
+        } else {
+            self.text.len()
+        };
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// An error about `token`, which should be a slice of this line
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(token), message)
+    }
+
+    /// An error about the character at the given column, starting at 1
+    pub fn error_at(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, message)
+    }
+
+    /// An error about something missing from the end of the line
+    pub fn error_at_end(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.text.chars().count() + 1, message)
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error_at_end(format!("expected `{}`", delimiter)))
+    }
+
+    /// Parses `token`, which should be a slice of this line
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        token
+            .parse()
+            .map_err(|e| self.error(token, format!("cannot parse `{}`: {}", token, e)))
+    }
+
+    /// The fields of the line separated by commas, without the spaces around them
+    pub fn comma_separated_fields(&self) -> impl Iterator<Item = &'a str> {
+        self.text.split(',').map(str::trim)
+    }
+
+    /// Parses the line as numbers separated by commas, such as `3,4,3,1,2`
+    pub fn comma_separated<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.comma_separated_fields()
+            .map(|field| self.parse(field))
+            .collect()
+    }
+
+    /// The characters of the line, along with the column each one is at
+    pub fn chars(&self) -> impl Iterator<Item = (usize, char)> + 'a {
+        self.text.chars().enumerate().map(|(i, c)| (i + 1, c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_are_separated_by_blank_lines() {
+        let sections: Vec<Vec<(usize, &str)>> = sections("\n7,4\n\n22 13\n 8  2\n\n\n3\n")
+            .map(|section| {
+                section
+                    .iter()
+                    .map(|line| (line.number(), line.text()))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            sections,
+            [
+                vec![(2, "7,4")],
+                vec![(4, "22 13"), (5, " 8  2")],
+                vec![(8, "3")]
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnostics_show_the_line_around_the_error() {
+        let error = ParseError::new(2, 3, "expected a number");
+        assert_eq!(
+            error.diagnostic("1\n2\tx\n"),
+            "invalid input at line 2, column 3: expected a number\n  |\n2 | 2\tx\n  |  \t^"
+        );
+
+        let line = format!("{}x{}", "0".repeat(100), "0".repeat(100));
+        let error = ParseError::new(1, 101, "expected a digit");
+        let diagnostic = error.diagnostic(&line);
+        let shown = format!("...{}x{}...", "0".repeat(40), "0".repeat(39));
+        assert!(diagnostic.ends_with(&format!("1 | {}\n  | {}^", shown, " ".repeat(43))));
+    }
+
+    #[test]
+    fn comma_separated_points_at_the_bad_number() {
+        let line = lines("3, 4,x,1").next().unwrap();
+        assert_eq!(
+            line.comma_separated_fields().collect::<Vec<_>>(),
+            ["3", "4", "x", "1"]
+        );
+        let error = line.comma_separated::<u8>().unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 6));
+        let line = lines("3,4,3,1,2").next().unwrap();
+        assert_eq!(line.comma_separated::<u8>().unwrap(), [3, 4, 3, 1, 2]);
+    }
+}
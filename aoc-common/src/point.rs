@@ -0,0 +1,301 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a 2D plane.
+///
+/// `x` grows to the right and `y` grows downwards, matching how puzzle
+/// inputs are laid out, so `y` is the row and `x` the column of a grid cell.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two points, e.g. a step in some direction
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Reduces each component to -1, 0 or 1, turning a difference into a single step
+    pub fn signum(self) -> Vec2 {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The `(row, column)` of this point in a grid, if neither is negative
+    pub fn to_grid_index(self) -> Option<(usize, usize)> {
+        let row = usize::try_from(self.y).ok()?;
+        let column = usize::try_from(self.x).ok()?;
+        Some((row, column))
+    }
+
+    pub fn from_grid_index((row, column): (usize, usize)) -> Point {
+        Point::new(column as i64, row as i64)
+    }
+
+    /// The points above, below, left and right of this one
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        ORTHOGONAL.into_iter().map(move |offset| self + offset)
+    }
+
+    /// All eight points surrounding this one, including the diagonal ones
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        ALL_AROUND.into_iter().map(move |offset| self + offset)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Vec2) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// A single step in this direction
+    pub const fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().opposite()
+    }
+}
+
+/// One of the four diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diagonal {
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Diagonal {
+    pub const ALL: [Diagonal; 4] = [
+        Diagonal::UpLeft,
+        Diagonal::UpRight,
+        Diagonal::DownLeft,
+        Diagonal::DownRight,
+    ];
+
+    /// A single step in this direction
+    pub const fn offset(self) -> Vec2 {
+        match self {
+            Diagonal::UpLeft => Point::new(-1, -1),
+            Diagonal::UpRight => Point::new(1, -1),
+            Diagonal::DownLeft => Point::new(-1, 1),
+            Diagonal::DownRight => Point::new(1, 1),
+        }
+    }
+}
+
+/// Offsets of the points above, below, left and right of a point
+pub const ORTHOGONAL: [Vec2; 4] = [
+    Direction::Up.offset(),
+    Direction::Down.offset(),
+    Direction::Left.offset(),
+    Direction::Right.offset(),
+];
+
+/// Offsets of all eight points surrounding a point, including the diagonal ones
+pub const ALL_AROUND: [Vec2; 8] = [
+    Diagonal::UpLeft.offset(),
+    Direction::Up.offset(),
+    Diagonal::UpRight.offset(),
+    Direction::Right.offset(),
+    Diagonal::DownRight.offset(),
+    Direction::Down.offset(),
+    Diagonal::DownLeft.offset(),
+    Direction::Left.offset(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manhattan_distance_counts_steps_along_both_axes() {
+        assert_eq!(Point::new(1, -2).manhattan_distance(Point::new(-3, 4)), 10);
+        assert_eq!(Point::ORIGIN.manhattan_distance(Point::ORIGIN), 0);
+        assert_eq!(
+            Point::new(i64::MIN, 0).manhattan_distance(Point::new(i64::MAX, 0)),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn grid_indices_round_trip() {
+        assert_eq!(Point::new(3, 5).to_grid_index(), Some((5, 3)));
+        assert_eq!(Point::from_grid_index((5, 3)), Point::new(3, 5));
+        assert_eq!(Point::new(-1, 5).to_grid_index(), None);
+        assert_eq!(Point::new(3, -1).to_grid_index(), None);
+        for position in [(0, 0), (0, 7), (7, 0), (12, 34)] {
+            assert_eq!(
+                Point::from_grid_index(position).to_grid_index(),
+                Some(position)
+            );
+        }
+    }
+
+    #[test]
+    fn neighbors_come_in_a_fixed_order() {
+        let point = Point::new(1, 1);
+        let neighbors: Vec<Point> = point.neighbors4().collect();
+        assert_eq!(
+            neighbors,
+            [
+                Point::new(1, 0),
+                Point::new(1, 2),
+                Point::new(0, 1),
+                Point::new(2, 1)
+            ]
+        );
+        // Clockwise, starting at the top left
+        let neighbors: Vec<Point> = point.neighbors8().collect();
+        assert_eq!(
+            neighbors,
+            [
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+                Point::new(1, 2),
+                Point::new(0, 2),
+                Point::new(0, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn directions_turn_and_step() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Right.turn_left(), Direction::Up);
+
+        let mut point = Point::new(2, 3);
+        point += Direction::Up.offset() * 3;
+        assert_eq!(point, Point::new(2, 0));
+        point -= Direction::Left.offset();
+        assert_eq!(point, Point::new(3, 0));
+        assert_eq!(Point::new(5, -4) - Point::new(2, 3), Point::new(3, -7));
+        assert_eq!(
+            (Point::new(5, -4) - Point::new(2, 3)).signum(),
+            Point::new(1, -1)
+        );
+    }
+
+    #[test]
+    fn diagonals_combine_two_directions() {
+        let combined = [
+            (Diagonal::UpLeft, Direction::Up, Direction::Left),
+            (Diagonal::UpRight, Direction::Up, Direction::Right),
+            (Diagonal::DownLeft, Direction::Down, Direction::Left),
+            (Diagonal::DownRight, Direction::Down, Direction::Right),
+        ];
+        for (diagonal, vertical, horizontal) in combined {
+            assert_eq!(diagonal.offset(), vertical.offset() + horizontal.offset());
+        }
+        assert_eq!(Diagonal::ALL.len(), combined.len());
+        assert_eq!(
+            Point::new(4, 4) + Diagonal::UpLeft.offset() * 4,
+            Point::ORIGIN
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Graphs whose nodes are looked up by name, with the searches the puzzles keep needing.
+
+mod search;
+mod shortest_path;
+
+pub use shortest_path::{ShortestPaths, Weight};
+
+use aoc_common::{Grid, Point};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Identifies a node of a [`Graph`].
+///
+/// Ids are handed out in the order nodes are added, starting at 0,
+/// so they can index into a `Vec` with an entry per node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A directed graph with nodes named by `N` and edges weighted by `E`.
+///
+/// Each name is interned once, so the searches work on [`NodeId`]s
+/// and never have to hash or compare names.
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    names: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<(NodeId, E)>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Graph {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N: Hash + Eq + Clone, E> Graph<N, E> {
+    pub fn new() -> Graph<N, E> {
+        Graph::default()
+    }
+
+    /// The id of the node with this name, adding the node if it is new
+    pub fn add_node(&mut self, name: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        let id = NodeId(self.names.len());
+        self.names.push(name.clone());
+        self.ids.insert(name, id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// The id of the node with this name, if it has been added
+    pub fn id(&self, name: &N) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Adds an edge from `from` to `to`, adding either node if it is new
+    pub fn add_edge(&mut self, from: N, to: N, weight: E) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from.0].push((to, weight));
+    }
+}
+
+impl<N: Hash + Eq + Clone, E: Clone> Graph<N, E> {
+    /// Adds an edge in each direction between `a` and `b`
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: E) {
+        self.add_edge(a.clone(), b.clone(), weight.clone());
+        self.add_edge(b, a, weight);
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn name(&self, id: NodeId) -> &N {
+        &self.names[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every node, in the order they were added
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.names.len()).map(NodeId)
+    }
+
+    /// The edges leaving `id`, in the order they were added
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, E)] {
+        &self.edges[id.0]
+    }
+
+    /// The nodes that edges leaving `id` lead to
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id.0].iter().map(|&(to, _)| to)
+    }
+
+    /// Turns a list of ids into the names of the nodes
+    pub fn names<'a>(&'a self, path: &[NodeId]) -> Vec<&'a N> {
+        path.iter().map(|&id| self.name(id)).collect()
+    }
+}
+
+impl<W> Graph<Point, W> {
+    /// A graph with a node for every cell of `grid`, and edges between orthogonal neighbors.
+    ///
+    /// Moving onto a cell costs `weight` of that cell. Nodes are added row by row,
+    /// so the node for `(row, column)` has the index `row * grid.width() + column`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut weight: impl FnMut(&T) -> W) -> Graph<Point, W> {
+        let names: Vec<Point> = grid.points().collect();
+        let ids = names
+            .iter()
+            .enumerate()
+            .map(|(index, &point)| (point, NodeId(index)))
+            .collect();
+        let edges = grid
+            .positions()
+            .map(|position| {
+                grid.neighbors4(position)
+                    .map(|(row, column)| {
+                        let id = NodeId(row * grid.width() + column);
+                        (id, weight(&grid[(row, column)]))
+                    })
+                    .collect()
+            })
+            .collect();
+        Graph { names, ids, edges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_interned_once() {
+        let mut graph = Graph::new();
+        graph.add_undirected_edge("start", "A", ());
+        graph.add_undirected_edge("A", "end", ());
+
+        assert_eq!(graph.len(), 3);
+        let a = graph.id(&"A").unwrap();
+        assert_eq!(graph.name(a), &"A");
+        assert_eq!(
+            graph.names(&graph.neighbors(a).collect::<Vec<_>>()),
+            [&"start", &"end"]
+        );
+        assert_eq!(graph.id(&"b"), None);
+    }
+
+    #[test]
+    fn grid_edges_cost_the_cell_entered() {
+        let grid = Grid::from_cells(2, 2, vec![1, 2, 3, 4]);
+        let graph = Graph::from_grid(&grid, |&cell| cell);
+
+        let origin = graph.id(&Point::ORIGIN).unwrap();
+        let mut edges = graph.edges(origin).to_vec();
+        edges.sort();
+        assert_eq!(edges, [(NodeId(1), 2), (NodeId(2), 3)]);
+        assert_eq!(graph.id(&Point::new(1, 1)), Some(NodeId(3)));
+    }
+}
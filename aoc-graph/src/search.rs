@@ -0,0 +1,134 @@
+use crate::{Graph, NodeId, ShortestPaths};
+use std::collections::VecDeque;
+
+impl<N, E> Graph<N, E> {
+    /// The nodes reachable from `start`, in depth-first order
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            if std::mem::replace(&mut seen[id.0], true) {
+                continue;
+            }
+            order.push(id);
+            // Pushed in reverse, so the first edge is followed first
+            let edges = self.edges(id).iter().rev();
+            stack.extend(edges.map(|&(to, _)| to).filter(|to| !seen[to.0]));
+        }
+        order
+    }
+
+    /// The fewest edges needed to reach each node from `start`, ignoring the weights
+    pub fn bfs(&self, start: NodeId) -> ShortestPaths<usize> {
+        let mut paths = ShortestPaths::new(start, self.len());
+        let mut queue = VecDeque::from([start]);
+        while let Some(id) = queue.pop_front() {
+            let distance = paths.distance(id).unwrap();
+            for to in self.neighbors(id) {
+                if paths.distance(to).is_none() {
+                    paths.set(to, distance + 1, id);
+                    queue.push_back(to);
+                }
+            }
+        }
+        paths
+    }
+
+    /// Every path from `start` to `end`, found depth first.
+    ///
+    /// A path is only extended to a node when `can_enter(path, node)` allows it,
+    /// where `path` is the path so far. Paths stop at `end`. As nothing else stops
+    /// a path from going round in circles, `can_enter` has to for graphs with cycles.
+    pub fn all_paths(
+        &self,
+        start: NodeId,
+        end: NodeId,
+        mut can_enter: impl FnMut(&[NodeId], NodeId) -> bool,
+    ) -> Vec<Vec<NodeId>> {
+        let mut paths = Vec::new();
+        let mut path = vec![start];
+        // The next edge to try from each node on the path
+        let mut next_edge = vec![0];
+        while let Some(edge) = next_edge.last_mut() {
+            let id = *path.last().unwrap();
+            let Some(&(to, _)) = self.edges(id).get(*edge) else {
+                path.pop();
+                next_edge.pop();
+                continue;
+            };
+            *edge += 1;
+            if !can_enter(&path, to) {
+                continue;
+            }
+            path.push(to);
+            if to == end {
+                paths.push(path.clone());
+                path.pop();
+            } else {
+                next_edge.push(0);
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    fn caves() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (a, b) in [
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "b"),
+            ("A", "end"),
+            ("b", "end"),
+            ("b", "d"),
+        ] {
+            graph.add_undirected_edge(a, b, ());
+        }
+        graph
+    }
+
+    #[test]
+    fn dfs_follows_the_first_edge_first() {
+        let graph = caves();
+        let order = graph.dfs(graph.id(&"start").unwrap());
+        assert_eq!(graph.names(&order), [&"start", &"A", &"b", &"end", &"d"]);
+    }
+
+    #[test]
+    fn bfs_counts_edges() {
+        let graph = caves();
+        let paths = graph.bfs(graph.id(&"start").unwrap());
+        let d = graph.id(&"d").unwrap();
+        assert_eq!(paths.distance(d), Some(2));
+        assert_eq!(
+            graph.names(&paths.path_to(d).unwrap()),
+            [&"start", &"b", &"d"]
+        );
+    }
+
+    #[test]
+    fn all_paths_respects_can_enter() {
+        let graph = caves();
+        let (start, end) = (graph.id(&"start").unwrap(), graph.id(&"end").unwrap());
+        let mut paths: Vec<Vec<&str>> = graph
+            .all_paths(start, end, |path, to| !path.contains(&to))
+            .iter()
+            .map(|path| graph.names(path).into_iter().copied().collect())
+            .collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                vec!["start", "A", "b", "end"],
+                vec!["start", "A", "end"],
+                vec!["start", "b", "A", "end"],
+                vec!["start", "b", "end"],
+            ]
+        );
+    }
+}
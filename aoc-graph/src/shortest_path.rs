@@ -0,0 +1,170 @@
+use crate::{Graph, NodeId};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::Add;
+
+/// Edge weights that paths can be measured in
+pub trait Weight: Copy + Ord + Add<Output = Self> {
+    /// The length of a path without any edges
+    const ZERO: Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            const ZERO: Self = 0;
+        })*
+    };
+}
+
+impl_weight!(u8, u16, u32, u64, usize, i32, i64);
+
+/// The shortest distance from one node to every node it can reach,
+/// along with how to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths<W> {
+    start: NodeId,
+    distances: Vec<Option<W>>,
+    previous: Vec<Option<NodeId>>,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    pub(crate) fn new(start: NodeId, len: usize) -> ShortestPaths<W> {
+        let mut distances = vec![None; len];
+        distances[start.0] = Some(W::ZERO);
+        ShortestPaths {
+            start,
+            distances,
+            previous: vec![None; len],
+        }
+    }
+
+    pub(crate) fn set(&mut self, id: NodeId, distance: W, previous: NodeId) {
+        self.distances[id.0] = Some(distance);
+        self.previous[id.0] = Some(previous);
+    }
+
+    pub fn start(&self) -> NodeId {
+        self.start
+    }
+
+    /// The length of the shortest path to `id`, if it can be reached
+    pub fn distance(&self, id: NodeId) -> Option<W> {
+        self.distances[id.0]
+    }
+
+    /// The shortest path from the start to `id`, including both ends
+    pub fn path_to(&self, id: NodeId) -> Option<Vec<NodeId>> {
+        self.distance(id)?;
+        let mut path = vec![id];
+        while let Some(previous) = self.previous[path.last().unwrap().0] {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<N, W: Weight> Graph<N, W> {
+    /// The shortest paths from `start` to every node, using Dijkstra's algorithm.
+    ///
+    /// Weights must not be negative.
+    pub fn dijkstra(&self, start: NodeId) -> ShortestPaths<W> {
+        let mut paths = ShortestPaths::new(start, self.len());
+        let mut queue = BinaryHeap::from([Reverse((W::ZERO, start))]);
+        while let Some(Reverse((distance, id))) = queue.pop() {
+            // A shorter way here was found after this one was queued
+            if paths.distance(id) != Some(distance) {
+                continue;
+            }
+            for &(to, weight) in self.edges(id) {
+                let alt = distance + weight;
+                if paths.distance(to).is_none_or(|current| alt < current) {
+                    paths.set(to, alt, id);
+                    queue.push(Reverse((alt, to)));
+                }
+            }
+        }
+        paths
+    }
+
+    /// The shortest path from `start` to `goal` and its length, using A*.
+    ///
+    /// `heuristic` estimates the distance from a node to `goal`. It must never
+    /// overestimate, and must not drop by more than an edge's weight along that edge,
+    /// or the path found might not be the shortest one.
+    pub fn astar(
+        &self,
+        start: NodeId,
+        goal: NodeId,
+        mut heuristic: impl FnMut(NodeId) -> W,
+    ) -> Option<(W, Vec<NodeId>)> {
+        let mut paths = ShortestPaths::new(start, self.len());
+        let mut done = vec![false; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((heuristic(start), start))]);
+        while let Some(Reverse((_, id))) = queue.pop() {
+            if std::mem::replace(&mut done[id.0], true) {
+                continue;
+            }
+            let distance = paths.distance(id).unwrap();
+            if id == goal {
+                return Some((distance, paths.path_to(goal).unwrap()));
+            }
+            for &(to, weight) in self.edges(id) {
+                let alt = distance + weight;
+                if !done[to.0] && paths.distance(to).is_none_or(|current| alt < current) {
+                    paths.set(to, alt, id);
+                    queue.push(Reverse((alt + heuristic(to), to)));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    fn roads() -> Graph<char, u32> {
+        let mut graph = Graph::new();
+        for (a, b, weight) in [
+            ('a', 'b', 7),
+            ('a', 'c', 9),
+            ('a', 'f', 14),
+            ('b', 'c', 10),
+            ('b', 'd', 15),
+            ('c', 'd', 11),
+            ('c', 'f', 2),
+            ('d', 'e', 6),
+            ('e', 'f', 9),
+        ] {
+            graph.add_undirected_edge(a, b, weight);
+        }
+        graph.add_node('g');
+        graph
+    }
+
+    #[test]
+    fn dijkstra_finds_shortest_paths() {
+        let graph = roads();
+        let paths = graph.dijkstra(graph.id(&'a').unwrap());
+        let e = graph.id(&'e').unwrap();
+        assert_eq!(paths.distance(e), Some(20));
+        assert_eq!(
+            graph.names(&paths.path_to(e).unwrap()),
+            [&'a', &'c', &'f', &'e']
+        );
+        assert_eq!(paths.path_to(graph.id(&'g').unwrap()), None);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let graph = roads();
+        let (a, e) = (graph.id(&'a').unwrap(), graph.id(&'e').unwrap());
+        let (distance, path) = graph.astar(a, e, |_| 0).unwrap();
+        assert_eq!(distance, 20);
+        assert_eq!(graph.names(&path), [&'a', &'c', &'f', &'e']);
+        assert_eq!(graph.astar(a, graph.id(&'g').unwrap(), |_| 0), None);
+    }
+}
@@ -0,0 +1,119 @@
+//! Times parsing and both parts of every day against its checked in input.
+//!
+//! Run with `cargo bench -p aoc`, or only some days with e.g. `cargo bench -p aoc -- day09`.
+//! To compare an optimisation, save a baseline first with `-- --save-baseline before`
+//! and then run again with `-- --baseline before`.
+
+use aoc::days;
+use aoc_common::{InputSource, Part};
+use criterion::{black_box, Criterion};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+fn bench_days(c: &mut Criterion) {
+    for solution in days::SOLUTIONS {
+        let day = solution.day();
+        let input = match InputSource::Default.read(day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {:02}: {}", day, e);
+                continue;
+            }
+        };
+        if let Err(e) = solution.parse(&input) {
+            eprintln!("Skipping day {:02}: {}", day, e);
+            continue;
+        }
+
+        let mut group = c.benchmark_group(format!("day{:02}", day));
+        group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&input))));
+        for part in Part::ALL {
+            // Unsolved parts have nothing to measure
+            if solution.solve(part, &input).is_err() {
+                continue;
+            }
+            group.bench_function(format!("part{}", part), |b| {
+                b.iter(|| solution.solve(part, black_box(&input)))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Where criterion keeps its measurements, following the same rules it does
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join("target")
+        })
+        .join("criterion")
+}
+
+/// The mean time of a benchmark, if it was measured since `since`
+fn mean_time(group: &str, step: &str, since: SystemTime) -> Option<Duration> {
+    let path = criterion_dir()
+        .join(group)
+        .join(step)
+        .join("new")
+        .join("estimates.json");
+    if fs::metadata(&path).ok()?.modified().ok()? < since {
+        return None;
+    }
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let nanos = estimates["mean"]["point_estimate"].as_f64()?;
+    Some(Duration::from_secs_f64(nanos / 1e9))
+}
+
+/// Prints the mean times measured by this run as one table, a row per day
+fn print_summary(since: SystemTime) {
+    let mut rows = Vec::new();
+    for solution in days::SOLUTIONS {
+        let group = format!("day{:02}", solution.day());
+        let times = STEPS.map(|step| mean_time(&group, step, since));
+        if times.iter().any(Option::is_some) {
+            rows.push((solution.day(), times));
+        }
+    }
+    if rows.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "{:<4} | {:>12} | {:>12} | {:>12}",
+        "Day", "Parse", "Part 1", "Part 2"
+    );
+    println!("{:-<4}-+-{:->12}-+-{:->12}-+-{:->12}", "", "", "", "");
+    for (day, times) in rows {
+        let [parse, part1, part2] = times.map(|time| match time {
+            Some(time) => format!("{:.1?}", time),
+            None => "-".to_string(),
+        });
+        println!(
+            "{:<4} | {:>12} | {:>12} | {:>12}",
+            format!("{:02}", day),
+            parse,
+            part1,
+            part2
+        );
+    }
+}
+
+fn main() {
+    let started = SystemTime::now();
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_days(&mut criterion);
+    criterion.final_summary();
+    print_summary(started);
+}
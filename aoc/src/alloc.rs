@@ -0,0 +1,182 @@
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+/// What the current thread has allocated since it started
+struct Counters {
+    allocations: Cell<u64>,
+    bytes: Cell<u64>,
+    /// Can go below zero when memory allocated by another thread is freed here
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+impl Counters {
+    fn allocated(&self, size: usize) {
+        self.allocations.set(self.allocations.get() + 1);
+        self.bytes.set(self.bytes.get() + size as u64);
+        self.live.set(self.live.get() + size as i64);
+        self.peak.set(self.peak.get().max(self.live.get()));
+    }
+
+    fn freed(&self, size: usize) {
+        self.live.set(self.live.get() - size as i64);
+    }
+}
+
+/// The system allocator, counting what each thread allocates once [`start_counting`] is called.
+///
+/// Counts are kept per thread, so days solved at the same time on different
+/// threads don't count each other's allocations.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(|counters| counters.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(|counters| counters.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(|counters| counters.freed(layout.size()));
+    }
+
+    /// Counted as allocating the new size and freeing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(|counters| {
+                counters.allocated(new_size);
+                counters.freed(layout.size());
+            });
+        }
+        new_ptr
+    }
+}
+
+fn count(f: impl FnOnce(&Counters)) {
+    if COUNTING.load(Ordering::Relaxed) {
+        // The counters are gone while the thread is shutting down, and then nothing is measured anyway
+        let _ = COUNTERS.try_with(f);
+    }
+}
+
+/// Makes [`CountingAllocator`] start counting, for when it is the global allocator
+pub fn start_counting() {
+    COUNTING.store(true, Ordering::Relaxed);
+}
+
+pub fn is_counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// The memory a piece of code allocated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// The total size of every allocation, including ones that were freed again
+    pub bytes: u64,
+    /// The most that was allocated and not yet freed at any one time
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, counting what it allocates on the current thread.
+///
+/// Everything is zero unless [`CountingAllocator`] is the global allocator and counting.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let (allocations, bytes, live) = COUNTERS.with(|counters| {
+        counters.peak.set(counters.live.get());
+        (
+            counters.allocations.get(),
+            counters.bytes.get(),
+            counters.live.get(),
+        )
+    });
+    let result = f();
+    let stats = COUNTERS.with(|counters| AllocStats {
+        allocations: counters.allocations.get() - allocations,
+        bytes: counters.bytes.get() - bytes,
+        peak_bytes: (counters.peak.get() - live).max(0) as u64,
+    });
+    (result, stats)
+}
+
+/// A size in bytes, in the largest unit that keeps it above 1, e.g. `1.5 MiB`
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn counts_allocations_and_the_peak() {
+        start_counting();
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let mut second: Vec<u8> = Vec::with_capacity(500);
+            second.push(1);
+            second
+        });
+        assert_eq!(
+            stats,
+            AllocStats {
+                allocations: 2,
+                bytes: 1500,
+                peak_bytes: 1000
+            }
+        );
+    }
+
+    #[test]
+    fn bytes_use_the_largest_unit() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Answers that have been accepted, by day, input and part.
+///
+/// Stored as TOML, with a table per day and input:
+///
+/// ```toml
+/// [day01.9c1f0e2a4b7d3e58]
+/// part1 = "1154"
+/// part2 = "1127"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>);
+
+impl Answers {
+    /// Reads the answers from `path`, starting out empty if it doesn't exist yet
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(with_path(path, e)),
+        };
+        toml::from_str(&text)
+            .map_err(|e| with_path(path, io::Error::new(io::ErrorKind::InvalidData, e)))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text = toml::to_string(self)
+            .map_err(|e| with_path(path, io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        fs::write(path, text).map_err(|e| with_path(path, e))
+    }
+
+    pub fn get(&self, day: u8, input: InputHash, part: Part) -> Option<Answer> {
+        self.0
+            .get(&day_key(day))?
+            .get(&input.to_string())?
+            .get(&part_key(part))
+            .map(|answer| Answer::parse(answer))
+    }
+
+    /// Records an answer, replacing whatever was recorded before
+    pub fn insert(&mut self, day: u8, input: InputHash, part: Part, answer: &Answer) {
+        self.0
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default()
+            .insert(part_key(part), answer.to_string());
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{}", part)
+}
+
+pub(crate) fn with_path(path: &Path, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+/// The checked in `answers.toml` at the root of the workspace
+pub fn default_answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+/// Identifies a puzzle input, so answers are only compared for the input they were given for.
+///
+/// This is a 64-bit FNV-1a hash, which unlike the standard library's hashers
+/// is guaranteed to stay the same between builds. Inputs are hashed as read by
+/// [`InputSource::read`](aoc_common::InputSource::read), after their line endings are normalised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputHash(u64);
+
+impl InputHash {
+    pub fn of(input: &str) -> InputHash {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in input.bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        InputHash(hash)
+    }
+}
+
+impl fmt::Display for InputHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_hash_is_fnv1a() {
+        assert_eq!(InputHash::of("").to_string(), "cbf29ce484222325");
+        assert_eq!(InputHash::of("a").to_string(), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn answers_survive_a_round_trip() {
+        let input = InputHash::of("6,10\n0,14\n");
+        let mut answers = Answers::default();
+        answers.insert(13, input, Part::One, &Answer::Integer(17));
+        let picture = Answer::Block("#####\n#...#\n#####".to_string());
+        answers.insert(13, input, Part::Two, &picture);
+
+        let text = toml::to_string(&answers).unwrap();
+        let parsed: Answers = toml::from_str(&text).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.get(13, input, Part::One), Some(Answer::Integer(17)));
+        assert_eq!(parsed.get(13, input, Part::Two), Some(picture));
+        assert_eq!(parsed.get(13, InputHash::of(""), Part::One), None);
+    }
+}
@@ -0,0 +1,214 @@
+use aoc_common::Part;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Where the puzzles are, unless `AOC_BASE_URL` or `--base-url` says otherwise
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2021";
+
+/// The environment variable holding the value of the website's `session` cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable that replaces [`DEFAULT_BASE_URL`]
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Talks to the Advent of Code website, or whatever stands in for it.
+///
+/// Puzzle inputs differ between accounts, so requests are made with the
+/// session cookie of the account that is logged in.
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+            .build();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+        }
+    }
+
+    /// A client for `base_url`, or `AOC_BASE_URL` when it isn't given, using the session in `AOC_SESSION`
+    pub fn from_env(base_url: Option<String>) -> Client {
+        let base_url = base_url
+            .or_else(|| env::var(BASE_URL_VAR).ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        Client::new(&base_url, session)
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Downloads the puzzle input of the given day
+    pub fn input(&self, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        read_response(response)
+    }
+
+    /// Sends an answer to a part of the given day, returning the page that came back
+    pub fn post_answer(&self, day: u8, part: Part, answer: &str) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}/day/{}/answer", self.base_url, day);
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(&[("level", &level), ("answer", answer)]);
+        read_response(response)
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, FetchError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| FetchError::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, response)) => Err(FetchError::Status {
+            status,
+            message: response.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Transport(e)) => Err(FetchError::Transport(e.to_string())),
+    }
+}
+
+/// What [`fetch_input`] did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input had been downloaded before, so nothing was requested
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the day's input to `path`, unless `path` already exists.
+///
+/// Inputs never change once they are available, so there is no reason to
+/// ask for one twice. The file only appears once the whole input has been
+/// received, so a download that fails part way is tried again next time.
+pub fn fetch_input(client: &Client, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.input(day)?;
+
+    let io_error = |e| FetchError::Io(path.to_path_buf(), e);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(io_error)?;
+    fs::rename(&partial, path).map_err(io_error)?;
+    Ok(Fetched::Downloaded)
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// `AOC_SESSION` isn't set
+    MissingSession,
+    /// The server answered, but with an error
+    Status { status: u16, message: String },
+    /// The server couldn't be reached, or the connection broke
+    Transport(String),
+    /// The input couldn't be saved
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "{} isn't set, it needs to hold the session cookie of a logged in browser",
+                SESSION_VAR
+            ),
+            FetchError::Status { status, message } => {
+                write!(f, "the server answered with status {}", status)?;
+                match message.trim() {
+                    "" => Ok(()),
+                    message => write!(f, ": {}", message),
+                }
+            }
+            FetchError::Transport(e) => write!(f, "{}", e),
+            FetchError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::StandIn;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once_with_the_session_cookie() {
+        let server = StandIn::serve(vec![(200, "199\n200\n208\n")]);
+        let client = Client::new(server.base_url(), Some("53616c7465".to_string()));
+        let dir = temp_dir("once");
+        let path = dir.join("day01").join("input.txt");
+
+        assert_eq!(fetch_input(&client, 1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "199\n200\n208\n");
+        // The stand-in only answers once, so asking again would fail
+        assert_eq!(fetch_input(&client, 1, &path).unwrap(), Fetched::Cached);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method_and_path, "GET /2021/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=53616c7465"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn errors_leave_nothing_behind() {
+        let message = "Please don't repeatedly request this endpoint before it unlocks!";
+        let server = StandIn::serve(vec![(404, message)]);
+        let client = Client::new(server.base_url(), Some("53616c7465".to_string()));
+        let dir = temp_dir("errors");
+        let path = dir.join("input.txt");
+
+        let e = fetch_input(&client, 25, &path).unwrap_err();
+        assert!(
+            matches!(e, FetchError::Status { status: 404, .. }),
+            "{:?}",
+            e
+        );
+        assert!(e.to_string().ends_with(message));
+        assert!(!path.exists());
+        server.requests();
+
+        let client = Client::new("http://127.0.0.1:9", None);
+        let e = fetch_input(&client, 25, &path).unwrap_err();
+        assert!(matches!(e, FetchError::MissingSession), "{:?}", e);
+    }
+}
@@ -0,0 +1,782 @@
+//! Random puzzle inputs, for stress testing the parsers and solvers
+//! at sizes far beyond the checked in inputs.
+//!
+//! Every generator follows the rules stated in its puzzle, so its inputs can be solved,
+//! and the same seed and size always give the same input.
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Write;
+
+/// Writes the input for one day, scaled by the given size
+pub struct Generator {
+    pub day: u8,
+    /// What the size counts, e.g. "lines" or "boards"
+    pub unit: &'static str,
+    /// About the size of a real puzzle input
+    pub default_size: usize,
+    generate: fn(&mut ChaCha8Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+/// Every day that has a generator, in calendar order
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        unit: "depths",
+        default_size: 2000,
+        generate: sonar_sweep,
+    },
+    Generator {
+        day: 2,
+        unit: "commands",
+        default_size: 1000,
+        generate: dive,
+    },
+    Generator {
+        day: 3,
+        unit: "numbers",
+        default_size: 1000,
+        generate: binary_diagnostic,
+    },
+    Generator {
+        day: 4,
+        unit: "boards",
+        default_size: 100,
+        generate: giant_squid,
+    },
+    Generator {
+        day: 5,
+        unit: "lines",
+        default_size: 500,
+        generate: hydrothermal_venture,
+    },
+    Generator {
+        day: 6,
+        unit: "fish",
+        default_size: 300,
+        generate: lanternfish,
+    },
+    Generator {
+        day: 7,
+        unit: "crabs",
+        default_size: 1000,
+        generate: treachery_of_whales,
+    },
+    Generator {
+        day: 8,
+        unit: "entries",
+        default_size: 200,
+        generate: seven_segment_search,
+    },
+    Generator {
+        day: 9,
+        unit: "rows and columns",
+        default_size: 100,
+        generate: smoke_basin,
+    },
+    Generator {
+        day: 10,
+        unit: "lines",
+        default_size: 100,
+        generate: syntax_scoring,
+    },
+    Generator {
+        day: 11,
+        unit: "rows and columns",
+        default_size: 10,
+        generate: dumbo_octopus,
+    },
+    Generator {
+        day: 12,
+        unit: "caves",
+        default_size: 10,
+        generate: passage_pathing,
+    },
+    Generator {
+        day: 13,
+        unit: "dots",
+        default_size: 800,
+        generate: transparent_origami,
+    },
+    Generator {
+        day: 14,
+        unit: "elements in the template",
+        default_size: 20,
+        generate: extended_polymerization,
+    },
+    Generator {
+        day: 15,
+        unit: "rows and columns",
+        default_size: 100,
+        generate: chiton,
+    },
+    Generator {
+        day: 16,
+        unit: "packets",
+        default_size: 60,
+        generate: packet_decoder,
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Depths that mostly increase
+fn sonar_sweep(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut output = String::new();
+    let mut depth: i32 = rng.gen_range(100..200);
+    for _ in 0..size {
+        writeln!(output, "{}", depth).unwrap();
+        depth = (depth + rng.gen_range(-10..=30)).clamp(0, 20_000);
+    }
+    output
+}
+
+/// Commands that never take the submarine above the surface
+fn dive(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut output = String::new();
+    let mut depth = 0;
+    for _ in 0..size {
+        let units = rng.gen_range(1..=9);
+        let command = match rng.gen_range(0..20) {
+            0..=7 => "forward",
+            8..=14 => "down",
+            _ if units <= depth => "up",
+            _ => "down",
+        };
+        match command {
+            "down" => depth += units,
+            "up" => depth -= units,
+            _ => {}
+        }
+        writeln!(output, "{} {}", command, units).unwrap();
+    }
+    output
+}
+
+/// An odd number of distinct numbers, so no bit is ever equally common,
+/// picked so that both ratings narrow down to a single number
+fn binary_diagnostic(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let size = size | 1;
+    let mut width = 12;
+    while (1usize << width) < size * 2 {
+        width += 1;
+    }
+    loop {
+        let mut seen = HashSet::new();
+        let mut numbers = Vec::new();
+        while numbers.len() < size {
+            let number = rng.gen_range(0..1u64 << width);
+            if seen.insert(number) {
+                numbers.push(number);
+            }
+        }
+        if narrows_down(&numbers, width, true) && narrows_down(&numbers, width, false) {
+            let mut output = String::new();
+            for number in numbers {
+                writeln!(output, "{:0width$b}", number, width = width).unwrap();
+            }
+            return output;
+        }
+    }
+}
+
+/// Whether keeping the numbers with the most (or least) common bit, one bit at a time,
+/// never runs out of numbers before a single one is left
+fn narrows_down(numbers: &[u64], width: usize, most_common: bool) -> bool {
+    let mut numbers = numbers.to_vec();
+    for bit in (0..width).rev() {
+        if numbers.len() == 1 {
+            return true;
+        }
+        let ones = numbers.iter().filter(|&&n| n >> bit & 1 == 1).count();
+        if ones == 0 || ones == numbers.len() {
+            return false;
+        }
+        let keep_ones = (ones * 2 >= numbers.len()) == most_common;
+        numbers.retain(|&n| (n >> bit & 1 == 1) == keep_ones);
+    }
+    numbers.len() == 1
+}
+
+/// Every number from 0 to 99 is drawn, so every board wins eventually
+fn giant_squid(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut draws: Vec<u32> = (0..100).collect();
+    draws.shuffle(rng);
+    let draws: Vec<String> = draws.iter().map(u32::to_string).collect();
+    let mut output = draws.join(",");
+    output.push('\n');
+
+    let numbers: Vec<u32> = (0..100).collect();
+    for _ in 0..size.max(1) {
+        output.push('\n');
+        let board: Vec<u32> = numbers.choose_multiple(rng, 25).copied().collect();
+        for row in board.chunks(5) {
+            let row: Vec<String> = row.iter().map(|number| format!("{:>2}", number)).collect();
+            writeln!(output, "{}", row.join(" ")).unwrap();
+        }
+    }
+    output
+}
+
+/// Horizontal, vertical and diagonal lines within a 1000 by 1000 area
+fn hydrothermal_venture(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const MAX: i32 = 999;
+    let mut output = String::new();
+    for _ in 0..size {
+        let (x1, y1) = (rng.gen_range(0..=MAX), rng.gen_range(0..=MAX));
+        // A step away from the start, which never heads straight out of the area
+        let step = |rng: &mut ChaCha8Rng, start: i32| match start {
+            0 => 1,
+            MAX => -1,
+            _ if rng.gen() => 1,
+            _ => -1,
+        };
+        let (dx, dy) = match rng.gen_range(0..3) {
+            0 => (step(rng, x1), 0),
+            1 => (0, step(rng, y1)),
+            _ => (step(rng, x1), step(rng, y1)),
+        };
+        // How far the line can go before it leaves the area
+        let room = |start: i32, step: i32| match step {
+            1 => MAX - start,
+            -1 => start,
+            _ => MAX,
+        };
+        let length = rng.gen_range(1..=room(x1, dx).min(room(y1, dy)));
+        let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+        writeln!(output, "{},{} -> {},{}", x1, y1, x2, y2).unwrap();
+    }
+    output
+}
+
+fn lanternfish(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let timers: Vec<String> = (0..size.max(1))
+        .map(|_| rng.gen_range(1..=5).to_string())
+        .collect();
+    format!("{}\n", timers.join(","))
+}
+
+fn treachery_of_whales(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let positions: Vec<String> = (0..size.max(1))
+        .map(|_| rng.gen_range(0..2000).to_string())
+        .collect();
+    format!("{}\n", positions.join(","))
+}
+
+/// Every entry has all ten digits, wired up in its own random way
+fn seven_segment_search(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    let display = |rng: &mut ChaCha8Rng, wires: &[char], digit: usize| {
+        let mut segments: Vec<char> = DIGITS[digit]
+            .chars()
+            .map(|segment| wires[segment as usize - 'a' as usize])
+            .collect();
+        segments.shuffle(rng);
+        segments.into_iter().collect::<String>()
+    };
+    let mut output = String::new();
+    for _ in 0..size {
+        let mut wires: Vec<char> = "abcdefg".chars().collect();
+        wires.shuffle(rng);
+        let mut patterns: Vec<String> = (0..10).map(|digit| display(rng, &wires, digit)).collect();
+        patterns.shuffle(rng);
+        let outputs: Vec<String> = (0..4)
+            .map(|_| {
+                let digit = rng.gen_range(0..10);
+                display(rng, &wires, digit)
+            })
+            .collect();
+        writeln!(output, "{} | {}", patterns.join(" "), outputs.join(" ")).unwrap();
+    }
+    output
+}
+
+/// Basins walled off by nines, each sloping down to a single low point
+fn smoke_basin(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let side = size.max(5);
+    let index = |row: usize, column: usize| row * side + column;
+    let neighbors = move |i: usize| {
+        let (row, column) = (i / side, i % side);
+        [
+            (row > 0).then(|| i - side),
+            (row + 1 < side).then(|| i + side),
+            (column > 0).then(|| i - 1),
+            (column + 1 < side).then(|| i + 1),
+        ]
+        .into_iter()
+        .flatten()
+    };
+
+    loop {
+        // Grow a region around every seed, and wall each one off from the previous ones
+        let seeds = (side * side / 30).max(3);
+        let mut region = vec![usize::MAX; side * side];
+        let mut queue = VecDeque::new();
+        for seed in 0..seeds {
+            let cell = index(rng.gen_range(0..side), rng.gen_range(0..side));
+            if region[cell] == usize::MAX {
+                region[cell] = seed;
+                queue.push_back(cell);
+            }
+        }
+        while let Some(cell) = queue.pop_front() {
+            for neighbor in neighbors(cell) {
+                if region[neighbor] == usize::MAX {
+                    region[neighbor] = region[cell];
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        let mut wall: Vec<bool> = (0..side * side)
+            .map(|cell| neighbors(cell).any(|neighbor| region[neighbor] < region[cell]))
+            .collect();
+
+        // Heights rise by one with every step away from the low point,
+        // so cells too far away to stay below nine become walls as well,
+        // as do basins of a single cell.
+        // That can split a basin, so repeat until nothing changes.
+        let mut heights = vec![9u8; side * side];
+        loop {
+            let mut changed = false;
+            let mut distance = vec![usize::MAX; side * side];
+            for low_point in 0..side * side {
+                if wall[low_point] || distance[low_point] != usize::MAX {
+                    continue;
+                }
+                let mut basin = vec![low_point];
+                distance[low_point] = 0;
+                let mut queue = VecDeque::from([low_point]);
+                while let Some(cell) = queue.pop_front() {
+                    for neighbor in neighbors(cell) {
+                        if !wall[neighbor] && distance[neighbor] == usize::MAX {
+                            distance[neighbor] = distance[cell] + 1;
+                            basin.push(neighbor);
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+                for cell in basin.iter().copied() {
+                    if basin.len() == 1 || distance[cell] > 8 {
+                        wall[cell] = true;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                for cell in 0..side * side {
+                    if !wall[cell] {
+                        heights[cell] = distance[cell] as u8;
+                    }
+                }
+                break;
+            }
+        }
+
+        let basins = heights.iter().filter(|&&height| height == 0).count();
+        if basins >= 3 {
+            let mut output = String::new();
+            for row in heights.chunks(side) {
+                let row: String = row.iter().map(|height| height.to_string()).collect();
+                writeln!(output, "{}", row).unwrap();
+            }
+            return output;
+        }
+    }
+}
+
+/// Lines that are either corrupted or incomplete, with an odd number of incomplete ones
+fn syntax_scoring(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    let size = size.max(1);
+    let mut incomplete = rng.gen_range(0..=size / 2) * 2 + 1;
+    if incomplete > size {
+        incomplete -= 2;
+    }
+    let mut kinds: Vec<bool> = (0..size).map(|i| i < incomplete).collect();
+    kinds.shuffle(rng);
+
+    let mut output = String::new();
+    for is_incomplete in kinds {
+        let mut line = String::new();
+        let mut open = Vec::new();
+        let length = rng.gen_range(10..=40);
+        while line.len() < length || open.is_empty() {
+            if open.is_empty() || rng.gen_bool(0.55) {
+                let (opening, closing) = *BRACKETS.choose(rng).unwrap();
+                line.push(opening);
+                open.push(closing);
+            } else {
+                line.push(open.pop().unwrap());
+            }
+        }
+        if !is_incomplete {
+            let expected = *open.last().unwrap();
+            let (_, wrong) = *BRACKETS
+                .iter()
+                .filter(|(_, closing)| *closing != expected)
+                .collect::<Vec<_>>()
+                .choose(rng)
+                .unwrap();
+            line.push(*wrong);
+            for _ in 0..rng.gen_range(0..10) {
+                let (opening, closing) = *BRACKETS.choose(rng).unwrap();
+                line.push(if rng.gen() { opening } else { closing });
+            }
+        }
+        writeln!(output, "{}", line).unwrap();
+    }
+    output
+}
+
+/// Random energy levels, picked so that all octopuses end up flashing at the same time.
+///
+/// Few random grids much larger than the real one ever synchronise, so for larger
+/// grids, or when none of the random ones did, the levels are random but next to
+/// each other. The octopuses at the higher level flash first, when the others are
+/// at 9, and the flashes spread to all of them.
+fn dumbo_octopus(rng: &mut ChaCha8Rng, size: usize) -> String {
+    const MAX_RANDOM_OCTOPUS_SIDE: usize = 16;
+    const RANDOM_TRIES: usize = 20;
+
+    let side = size.max(1);
+    if side <= MAX_RANDOM_OCTOPUS_SIDE {
+        for _ in 0..RANDOM_TRIES {
+            let input = digits(rng, side, 0..=9);
+            let mut levels: Vec<u8> = input
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .map(|b| b - b'0')
+                .collect();
+            if (0..1000).any(|_| octopus_step(&mut levels, side) == side * side) {
+                return input;
+            }
+        }
+    }
+
+    let low = rng.gen_range(0..9);
+    let mut input = digits(rng, side, low..=low + 1);
+    // At least one octopus has to start the flashing
+    let first = rng.gen_range(0..side) * (side + 1) + rng.gen_range(0..side);
+    input.replace_range(first..=first, &(low + 1).to_string());
+    input
+}
+
+/// Moves the octopuses one step ahead, returning how many of them flashed
+fn octopus_step(levels: &mut [u8], side: usize) -> usize {
+    let mut flashing: Vec<usize> = Vec::new();
+    for (i, level) in levels.iter_mut().enumerate() {
+        *level += 1;
+        if *level == 10 {
+            flashing.push(i);
+        }
+    }
+    let mut flashed = 0;
+    while let Some(i) = flashing.pop() {
+        flashed += 1;
+        let (row, column) = (i / side, i % side);
+        for neighbor_row in row.saturating_sub(1)..=(row + 1).min(side - 1) {
+            for neighbor_column in column.saturating_sub(1)..=(column + 1).min(side - 1) {
+                let neighbor = neighbor_row * side + neighbor_column;
+                if neighbor != i {
+                    levels[neighbor] += 1;
+                    if levels[neighbor] == 10 {
+                        flashing.push(neighbor);
+                    }
+                }
+            }
+        }
+    }
+    for level in levels.iter_mut() {
+        if *level > 9 {
+            *level = 0;
+        }
+    }
+    flashed
+}
+
+/// A connected cave system where big caves are never next to each other,
+/// as that would allow endless paths
+fn passage_pathing(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let big = (size / 4).max(1);
+    let mut caves = vec!["start".to_string()];
+    for i in 0..size.max(2) {
+        let name = cave_name(i, i < big);
+        if name != "start" && name != "end" {
+            caves.push(name);
+        }
+    }
+    caves[1..].shuffle(rng);
+    caves.push("end".to_string());
+
+    let is_big = |cave: &str| cave.chars().all(|c| c.is_ascii_uppercase());
+    let mut connections = HashSet::new();
+    let connect = |a: usize, b: usize, connections: &mut HashSet<(usize, usize)>| {
+        if a != b && !(is_big(&caves[a]) && is_big(&caves[b])) {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    };
+    // Link every cave to an earlier small one, which keeps everything connected
+    for cave in 1..caves.len() {
+        loop {
+            let other = rng.gen_range(0..cave);
+            if !is_big(&caves[other]) {
+                connect(cave, other, &mut connections);
+                break;
+            }
+        }
+    }
+    for _ in 0..caves.len() / 2 {
+        let (a, b) = (rng.gen_range(0..caves.len()), rng.gen_range(0..caves.len()));
+        connect(a, b, &mut connections);
+    }
+
+    let mut connections: Vec<(usize, usize)> = connections.into_iter().collect();
+    connections.sort_unstable();
+    connections.shuffle(rng);
+    let mut output = String::new();
+    for (a, b) in connections {
+        writeln!(output, "{}-{}", caves[a], caves[b]).unwrap();
+    }
+    output
+}
+
+/// Names made of at least two letters, counting up from `aa` or `AA`
+fn cave_name(mut index: usize, big: bool) -> String {
+    let first = if big { b'A' } else { b'a' };
+    let mut name = Vec::new();
+    while name.len() < 2 || index > 0 {
+        name.push((first + (index % 26) as u8) as char);
+        index /= 26;
+    }
+    name.into_iter().rev().collect()
+}
+
+/// Dots that fold up into a 40 by 6 picture, like the real puzzle
+fn transparent_origami(rng: &mut ChaCha8Rng, size: usize) -> String {
+    // Every fold halves the paper, where the fold line is the size of the folded half
+    let fold_lines = |mut length: i64, folds: usize| {
+        let mut lines = Vec::new();
+        for _ in 0..folds {
+            lines.push(length);
+            length = length * 2 + 1;
+        }
+        lines
+    };
+    let x_folds = fold_lines(40, 5);
+    let y_folds = fold_lines(6, 7);
+
+    // Unfold randomly placed dots, mirroring each across every fold line half of the time
+    let mut dots = HashSet::new();
+    while dots.len() < size.max(1) {
+        let mut x = rng.gen_range(0..40);
+        let mut y = rng.gen_range(0..6);
+        for &line in &x_folds {
+            if rng.gen() {
+                x = 2 * line - x;
+            }
+        }
+        for &line in &y_folds {
+            if rng.gen() {
+                y = 2 * line - y;
+            }
+        }
+        dots.insert((x, y));
+    }
+    let mut dots: Vec<(i64, i64)> = dots.into_iter().collect();
+    dots.sort_unstable();
+    dots.shuffle(rng);
+
+    let mut output = String::new();
+    for (x, y) in dots {
+        writeln!(output, "{},{}", x, y).unwrap();
+    }
+    output.push('\n');
+
+    // The largest folds come first along each axis
+    let (mut x_folds, mut y_folds) = (x_folds, y_folds);
+    while !x_folds.is_empty() || !y_folds.is_empty() {
+        let along_x = y_folds.is_empty() || (!x_folds.is_empty() && rng.gen());
+        let (axis, line) = if along_x {
+            ('x', x_folds.pop().unwrap())
+        } else {
+            ('y', y_folds.pop().unwrap())
+        };
+        writeln!(output, "fold along {}={}", axis, line).unwrap();
+    }
+    output
+}
+
+/// A rule for every pair of ten random elements
+fn extended_polymerization(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut elements: Vec<char> = ('A'..='Z').collect();
+    elements.shuffle(rng);
+    elements.truncate(10);
+
+    let template: String = (0..size.max(2))
+        .map(|_| *elements.choose(rng).unwrap())
+        .collect();
+    let mut output = format!("{}\n\n", template);
+    let mut rules = Vec::new();
+    for &first in &elements {
+        for &second in &elements {
+            rules.push((first, second, *elements.choose(rng).unwrap()));
+        }
+    }
+    rules.shuffle(rng);
+    for (first, second, inserted) in rules {
+        writeln!(output, "{}{} -> {}", first, second, inserted).unwrap();
+    }
+    output
+}
+
+fn chiton(rng: &mut ChaCha8Rng, size: usize) -> String {
+    digits(rng, size.max(1), 1..=9)
+}
+
+/// A tree of packets, where comparisons always have exactly two sub-packets
+fn packet_decoder(rng: &mut ChaCha8Rng, size: usize) -> String {
+    let mut bits = packet(rng, size.max(1));
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    let hex: String = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = std::str::from_utf8(nibble).unwrap();
+            let value = u32::from_str_radix(nibble, 2).unwrap();
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    format!("{}\n", hex)
+}
+
+/// The bits of a packet and its sub-packets, which are `count` packets altogether.
+///
+/// Operators with room for more than one sub-packet have at least two, sharing the
+/// packets out evenly, so the tree is never more than about `log2(count)` deep.
+fn packet(rng: &mut ChaCha8Rng, count: usize) -> String {
+    let mut bits = format!("{:03b}", rng.gen_range(0..8));
+
+    // A comparison needs two more packets, any other operator at least one
+    let packet_type = match count {
+        1 => 4,
+        2 => *[0, 1, 2, 3].choose(rng).unwrap(),
+        _ => *[0, 1, 2, 3, 5, 6, 7].choose(rng).unwrap(),
+    };
+    write!(bits, "{:03b}", packet_type).unwrap();
+
+    if packet_type == 4 {
+        let groups = rng.gen_range(1..=4);
+        for group in 0..groups {
+            let last = group == groups - 1;
+            write!(
+                bits,
+                "{}{:04b}",
+                if last { 0 } else { 1 },
+                rng.gen_range(0..16)
+            )
+            .unwrap();
+        }
+        return bits;
+    }
+
+    let left = count - 1;
+    let sub_packets_count = match packet_type {
+        5..=7 => 2,
+        _ => rng.gen_range(left.min(2)..=left.min(5)),
+    };
+    let mut sub_packets = String::new();
+    for i in 0..sub_packets_count {
+        // The first few get one packet more when they can't be shared out evenly
+        let share = left / sub_packets_count + usize::from(i < left % sub_packets_count);
+        sub_packets.push_str(&packet(rng, share));
+    }
+    if sub_packets.len() < 1 << 15 && rng.gen() {
+        write!(bits, "0{:015b}", sub_packets.len()).unwrap();
+    } else {
+        write!(bits, "1{:011b}", sub_packets_count).unwrap();
+    }
+    bits.push_str(&sub_packets);
+    bits
+}
+
+/// A square map of single digits
+fn digits(rng: &mut ChaCha8Rng, side: usize, range: std::ops::RangeInclusive<u32>) -> String {
+    let mut output = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            output.push(char::from_digit(rng.gen_range(range.clone()), 10).unwrap());
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn every_day_has_a_generator() {
+        for solution in days::SOLUTIONS {
+            assert!(find(solution.day()).is_some(), "day {}", solution.day());
+        }
+    }
+
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        for generator in GENERATORS {
+            let solution = days::find(generator.day).unwrap();
+            // Well beyond a real input, as far as each solution allows in a debug build
+            let large = match generator.day {
+                // Paths multiply with every cave
+                12 => 16,
+                15 => 200,
+                _ => generator.default_size * 5,
+            };
+            // A few seeds at a size where even the slow solutions are quick, then a large one
+            let sizes = [(0, 12), (1, 12), (2, 12), (0, large)];
+            for (seed, size) in sizes {
+                let input = generator.generate(seed, size);
+                for result in [solution.part1(&input), solution.part2(&input)] {
+                    if let Err(e @ aoc_common::SolveError::Parse(_)) = result {
+                        panic!(
+                            "day {} seed {} size {}: {}\n{}",
+                            generator.day, seed, size, e, input
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_packet_trees_can_be_decoded() {
+        let input = find(16).unwrap().generate(0, 100_000);
+        let result = days::find(16).unwrap().part1(&input);
+        assert!(result.is_ok(), "{:?}", result);
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_input() {
+        for generator in GENERATORS {
+            assert_eq!(generator.generate(7, 20), generator.generate(7, 20));
+            assert_ne!(generator.generate(7, 20), generator.generate(8, 20));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod alloc;
+pub mod answers;
+pub mod days;
+pub mod fetch;
+pub mod generate;
+pub mod report;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+#[cfg(test)]
+mod stand_in;
@@ -0,0 +1,689 @@
+use aoc::alloc::{self, CountingAllocator};
+use aoc::answers::{self, Answers, InputHash};
+use aoc::days;
+use aoc::fetch::{self, Client, Fetched};
+use aoc::generate;
+use aoc::report::{self, DayRun, Format};
+use aoc::scaffold;
+use aoc::submit::{self, History, Outcome, Verdict};
+use aoc::watch::{self, Watcher};
+use aoc_common::{format_result, Answer, InputSource, Part, Solution, SolveError};
+use clap::{Args, Parser, Subcommand};
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Only counts anything once `--alloc` asks for it
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// How often `aoc watch` checks whether the inputs changed
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solutions are doing to stderr, `-vv` for even more detail.
+    /// `RUST_LOG` takes precedence when it is set, e.g. `RUST_LOG=day13=debug`.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the available days
+    List,
+    /// Solve one or more days
+    Run(RunArgs),
+    /// Solve days again and compare the answers with the recorded ones.
+    /// Fails when an answer changed, or when a part has no recorded answer and `--record` isn't given.
+    Verify {
+        /// Days to verify, e.g. `--day 7` or `--day 1,3,5`
+        #[arg(short, long, value_delimiter = ',')]
+        day: Vec<u8>,
+        /// Record answers that have no recorded answer yet.
+        /// Answers that changed are still reported and left as they were.
+        #[arg(long)]
+        record: bool,
+        /// The recorded answers, `answers.toml` at the root of the workspace by default
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Create the crate of a new day and add it to the workspace
+    New {
+        /// The day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Write a random input for a day to stdout
+    Gen {
+        /// The day to write an input for
+        #[arg(short, long)]
+        day: u8,
+        /// The same seed always gives the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// How large the input should be, e.g. the number of lines or boards.
+        /// Defaults to about the size of a real input.
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Solve a day again whenever its input changes, until stopped with Ctrl-C
+    Watch {
+        /// The day to solve
+        #[arg(short, long)]
+        day: u8,
+        /// Only solve the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Watch this file instead of the day's `input.txt` and named inputs.
+        /// Can be given more than once.
+        #[arg(short, long = "input", value_name = "PATH")]
+        inputs: Vec<PathBuf>,
+    },
+    /// Download a day's puzzle input, unless it has been downloaded before.
+    /// Needs the session cookie of a logged in browser in `AOC_SESSION`.
+    Fetch {
+        /// The day to download the input of
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Save it as the day's `inputs/NAME.txt` instead of its `input.txt`,
+        /// e.g. when fetching the input of another account
+        #[arg(short = 'n', long, value_name = "NAME")]
+        input_name: Option<String>,
+        /// Download from this URL instead of `AOC_BASE_URL`,
+        /// or https://adventofcode.com/2021 if that isn't set either
+        #[arg(long, value_name = "URL")]
+        base_url: Option<String>,
+    },
+    /// Solve a part of a day and send the answer to the website.
+    /// Answers that were rejected before aren't sent again,
+    /// and nothing is sent while the website asks to wait.
+    Submit(SubmitArgs),
+    /// Draw a picture of a day's grid, for the days that have one
+    Render {
+        /// The day to draw
+        #[arg(short, long)]
+        day: u8,
+        /// Read the puzzle input from this file instead of the day's `input.txt`,
+        /// `-` reads from stdin
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<String>,
+        /// Where to write the picture, `dayNN.png` by default.
+        /// Written as a PPM image if the file name ends in `.ppm`, otherwise as a PNG.
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+        /// How many pixels wide and high each cell of the grid is.
+        /// By default cells are made as large as fits in a picture of about 800 pixels.
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: Option<u32>,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Days to run, e.g. `--day 7` or `--day 1,3,5`
+    #[arg(short, long, value_delimiter = ',', conflicts_with = "all")]
+    day: Vec<u8>,
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Run every available day (the default when no day is given)
+    #[arg(long)]
+    all: bool,
+    /// Read the puzzle input from this file instead of the day's `input.txt`,
+    /// `-` reads from stdin. Only valid when running a single day.
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<String>,
+    /// Read each day's `inputs/NAME.txt` instead of its `input.txt`
+    #[arg(short = 'n', long, value_name = "NAME", conflicts_with_all = ["input", "all_inputs"])]
+    input_name: Option<String>,
+    /// Solve each day's `input.txt` and every input in its `inputs` directory,
+    /// showing the answers for each input side by side
+    #[arg(long, conflicts_with_all = ["input", "time", "repeat"])]
+    all_inputs: bool,
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+    /// Show how long parsing and each part took, and the total of all days
+    #[arg(short, long)]
+    time: bool,
+    /// Solve everything this many times and show the median and fastest times
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// How many days to solve at the same time, one per CPU by default.
+    /// Only one unless given when timing, as days running alongside slow each other down.
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Count the allocations each part makes, how many bytes they add up to
+    /// and the most that was allocated at any one time
+    #[arg(long, conflicts_with = "all_inputs")]
+    alloc: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The day to answer
+    #[arg(short, long)]
+    day: u8,
+    /// The part to answer
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Send this instead of the solution's answer,
+    /// e.g. the letters of an answer that is drawn as a picture
+    #[arg(short, long)]
+    answer: Option<String>,
+    /// Send the answer to this URL instead of `AOC_BASE_URL`,
+    /// or https://adventofcode.com/2021 if that isn't set either
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+    /// The answers submitted so far, `submissions.toml` at the root of the workspace by default
+    #[arg(long, value_name = "PATH")]
+    history: Option<PathBuf>,
+}
+
+fn select_days(days: &[u8]) -> Result<Vec<&'static dyn Solution>, String> {
+    if days.is_empty() {
+        return Ok(days::SOLUTIONS.to_vec());
+    }
+    days.iter()
+        .map(|&day| days::find(day).ok_or_else(|| format!("Day {} is not available", day)))
+        .collect()
+}
+
+fn select_parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(2) => vec![Part::Two],
+        _ => Part::ALL.to_vec(),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let solutions = match select_days(&args.day) {
+        Ok(solutions) => solutions,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = match args.input {
+        Some(_) if solutions.len() != 1 => {
+            eprintln!("--input can only be used when running a single day");
+            return ExitCode::FAILURE;
+        }
+        Some(path) => InputSource::from_arg(&path),
+        None => match args.input_name {
+            Some(name) => InputSource::Named(name),
+            None => InputSource::Default,
+        },
+    };
+    let days: Vec<(&dyn Solution, InputSource)> = solutions
+        .into_iter()
+        .flat_map(|solution| {
+            let sources = if args.all_inputs {
+                let names = aoc_common::input_names(solution.day());
+                let named = names.into_iter().map(InputSource::Named);
+                [InputSource::Default].into_iter().chain(named).collect()
+            } else {
+                vec![source.clone()]
+            };
+            sources.into_iter().map(move |source| (solution, source))
+        })
+        .collect();
+
+    let parts = select_parts(args.part);
+    if args.alloc {
+        alloc::start_counting();
+    }
+    let timing = args.time || args.repeat > 1;
+    let jobs = match args.jobs {
+        Some(jobs) => jobs as usize,
+        None if timing => 1,
+        None => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+    };
+    let runs = DayRun::solve_all(&days, &parts, args.repeat as usize, jobs);
+
+    let mut out = io::stdout().lock();
+    let written = if args.all_inputs && args.format == Format::Text {
+        report::write_side_by_side(&mut out, &runs, &parts)
+    } else {
+        report::write(&mut out, args.format, &runs, &parts, timing)
+    };
+    if let Err(e) = written {
+        eprintln!("Failed to write the results: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    if runs.iter().any(DayRun::failed) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(days: &[u8], record: bool, path: Option<PathBuf>) -> ExitCode {
+    let solutions = match select_days(days) {
+        Ok(solutions) => solutions,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let path = path.unwrap_or_else(answers::default_answers_path);
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read the recorded answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut changed = Vec::new();
+    let mut missing = Vec::new();
+    let mut failed = false;
+    let mut matched = 0;
+    let mut recorded = 0;
+    for solution in solutions {
+        println!("Day {:02}", solution.day());
+        let input = match InputSource::Default.read(solution.day()) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("  Failed to read input: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        let input_hash = InputHash::of(&input);
+        for part in Part::ALL {
+            let answer = match solution.solve(part, &input) {
+                Ok(answer) => answer,
+                Err(SolveError::Unimplemented) => continue,
+                Err(e @ SolveError::Parse(_)) => {
+                    let result = Err(e);
+                    println!(
+                        "  {}",
+                        format_result(part, &result, &input).replace('\n', "\n  ")
+                    );
+                    failed = true;
+                    continue;
+                }
+            };
+            match answers.get(solution.day(), input_hash, part) {
+                Some(expected) if expected == answer => {
+                    println!("  Part {}: ok", part);
+                    matched += 1;
+                }
+                Some(expected) => {
+                    println!("  Part {}: changed", part);
+                    let indent =
+                        |answer: &Answer| answer.to_string().replace('\n', "\n              ");
+                    println!("    Expected: {}", indent(&expected));
+                    println!("    Found:    {}", indent(&answer));
+                    changed.push((solution.day(), part));
+                }
+                None if record => {
+                    println!("  Part {}: recorded", part);
+                    answers.insert(solution.day(), input_hash, part, &answer);
+                    recorded += 1;
+                }
+                None => {
+                    println!("  Part {}: no recorded answer", part);
+                    missing.push((solution.day(), part));
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        if let Err(e) = answers.save(&path) {
+            eprintln!("Failed to record the answers: {}", e);
+            failed = true;
+        }
+    }
+
+    if !changed.is_empty() {
+        let parts: Vec<String> = changed
+            .iter()
+            .map(|(day, part)| format!("day {:02} part {}", day, part))
+            .collect();
+        println!();
+        println!("Changed: {}", parts.join(", "));
+    }
+
+    // Answers that can't be compared would otherwise let a broken input or hash pass unnoticed
+    if !missing.is_empty() {
+        let parts: Vec<String> = missing
+            .iter()
+            .map(|(day, part)| format!("day {:02} part {}", day, part))
+            .collect();
+        println!();
+        println!("No recorded answer: {}", parts.join(", "));
+        println!("Run again with --record to record them");
+    }
+    if matched == 0 && recorded == 0 && changed.is_empty() {
+        println!();
+        println!("No part was checked against a recorded answer");
+        failed = true;
+    }
+
+    if failed || !changed.is_empty() || !missing.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    let root = scaffold::workspace_root();
+    match scaffold::new_day(&root, day) {
+        Ok(written) => {
+            for path in written {
+                let path = path.strip_prefix(&root).unwrap_or(&path);
+                println!("Wrote {}", path.display());
+            }
+            println!(
+                "Day {} is ready to be solved in day{:02}/src/lib.rs",
+                day, day
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Failed to create day {}: {}", day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn gen(day: u8, seed: u64, size: Option<usize>) -> ExitCode {
+    let Some(generator) = generate::find(day) else {
+        eprintln!("Day {} has no input generator", day);
+        return ExitCode::FAILURE;
+    };
+    let size = size.unwrap_or(generator.default_size);
+    if size == 0 {
+        eprintln!(
+            "The size of day {} is the number of {}, which is {} in a real input",
+            day, generator.unit, generator.default_size
+        );
+        return ExitCode::FAILURE;
+    }
+    print!("{}", generator.generate(seed, size));
+    ExitCode::SUCCESS
+}
+
+fn watch(day: u8, part: Option<u8>, inputs: Vec<PathBuf>) -> ExitCode {
+    let Some(solution) = days::find(day) else {
+        eprintln!("Day {} is not available", day);
+        return ExitCode::FAILURE;
+    };
+    let paths = if inputs.is_empty() {
+        watch::default_paths(day)
+    } else {
+        inputs
+    };
+    let mut watcher = Watcher::new(paths);
+    let parts = select_parts(part);
+
+    let paths: Vec<String> = watcher
+        .paths()
+        .map(|path| path.display().to_string())
+        .collect();
+    eprintln!("Watching {}, press Ctrl-C to stop", paths.join(", "));
+    loop {
+        for path in watcher.changed() {
+            println!("{}", path.display());
+            let run = DayRun::solve(solution, &InputSource::File(path), &parts, 1);
+            let mut out = io::stdout().lock();
+            if let Err(e) = report::write(&mut out, Format::Text, &[run], &parts, true) {
+                eprintln!("Failed to write the results: {}", e);
+                return ExitCode::FAILURE;
+            }
+            println!();
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn fetch(day: u8, input_name: Option<String>, base_url: Option<String>) -> ExitCode {
+    let path = match &input_name {
+        Some(name) => aoc_common::named_input_path(day, name),
+        None => aoc_common::default_input_path(day),
+    };
+    let client = Client::from_env(base_url);
+    match fetch::fetch_input(&client, day, &path) {
+        Ok(Fetched::Cached) => {
+            println!(
+                "Already have the input of day {} in {}",
+                day,
+                path.display()
+            );
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded) => {
+            println!("Saved the input of day {} to {}", day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!(
+                "Failed to download the input of day {} from {}: {}",
+                day,
+                client.base_url(),
+                e
+            );
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(args: SubmitArgs) -> ExitCode {
+    let SubmitArgs {
+        day,
+        part,
+        answer,
+        base_url,
+        history,
+    } = args;
+    let part = select_parts(Some(part))[0];
+    let answer = match answer {
+        Some(answer) => Answer::parse(answer.trim()),
+        None => {
+            let Some(solution) = days::find(day) else {
+                eprintln!("Day {} is not available", day);
+                return ExitCode::FAILURE;
+            };
+            let result = InputSource::Default
+                .read(day)
+                .map_err(|e| format!("Failed to read input: {}", e))
+                .and_then(|input| {
+                    solution
+                        .solve(part, &input)
+                        .map_err(|e| format_result(part, &Err(e), &input))
+                });
+            match result {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+    if answer == "" {
+        eprintln!("Empty answer, nothing to submit");
+        return ExitCode::FAILURE;
+    }
+    if answer.is_block() {
+        eprintln!("The answer is a picture, read it and give it with --answer instead:");
+        eprintln!("{}", answer);
+        return ExitCode::FAILURE;
+    }
+
+    let path = history.unwrap_or_else(submit::default_history_path);
+    let mut history = match History::load(&path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read the submitted answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = Client::from_env(base_url);
+    let outcome = submit::submit(&client, &mut history, day, part, &answer, SystemTime::now());
+    if let Err(e) = history.save(&path) {
+        eprintln!("Failed to record the submitted answer: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to send the answer to {}: {}", client.base_url(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    print!("Day {:02} part {}: ", day, part);
+    match outcome {
+        Outcome::Submitted(Verdict::Correct) => {
+            println!("{} is the right answer", answer);
+            return ExitCode::SUCCESS;
+        }
+        Outcome::Submitted(Verdict::Incorrect { hint, wait }) => {
+            match hint {
+                Some(hint) => println!("{} is {}", answer, hint),
+                None => println!("{} is not the right answer", answer),
+            }
+            println!(
+                "The next answer can be sent in {}",
+                submit::format_wait(wait)
+            );
+        }
+        Outcome::Submitted(Verdict::TooRecent(wait)) => println!(
+            "{} wasn't looked at, as the last answer was sent too recently. Try again in {}",
+            answer,
+            submit::format_wait(wait)
+        ),
+        Outcome::Submitted(Verdict::WrongLevel) => {
+            println!("answers aren't being taken, it may be solved already")
+        }
+        Outcome::AlreadyAccepted(accepted) if accepted == answer => {
+            println!("{} was accepted already", answer);
+            return ExitCode::SUCCESS;
+        }
+        Outcome::AlreadyAccepted(accepted) => println!(
+            "{} was accepted already, so {} can't be right",
+            accepted, answer
+        ),
+        Outcome::AlreadyRejected(Some(hint)) => {
+            println!(
+                "{} was rejected before as {}, not sending it again",
+                answer, hint
+            )
+        }
+        Outcome::AlreadyRejected(None) => {
+            println!("{} was rejected before, not sending it again", answer)
+        }
+        Outcome::RuledOut { by, hint } => println!(
+            "{} was {}, so {} can't be right either, not sending it",
+            by, hint, answer
+        ),
+        Outcome::Cooldown(wait) => println!(
+            "the website asked to wait, {} can be sent in {}",
+            answer,
+            submit::format_wait(wait)
+        ),
+    }
+    ExitCode::FAILURE
+}
+
+fn render(day: u8, input: Option<String>, output: Option<PathBuf>, scale: Option<u32>) -> ExitCode {
+    let Some(solution) = days::find(day) else {
+        eprintln!("Day {} is not available", day);
+        return ExitCode::FAILURE;
+    };
+    let source = input.map_or(InputSource::Default, |path| InputSource::from_arg(&path));
+    let input = match source.read(day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let image = match solution.render(&input) {
+        Ok(image) => {
+            let cells = image.cells();
+            let scale = scale.map_or_else(
+                || (800 / cells.width().max(cells.height()).max(1)).max(1),
+                |scale| scale as usize,
+            );
+            image.with_scale(scale)
+        }
+        Err(SolveError::Unimplemented) => {
+            eprintln!("Day {} has nothing to draw", day);
+            return ExitCode::FAILURE;
+        }
+        Err(SolveError::Parse(e)) => {
+            eprintln!("{}", e.diagnostic(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("day{:02}.png", day)));
+    if let Err(e) = image.save(&output) {
+        eprintln!("Failed to write {}: {}", output.display(), e);
+        return ExitCode::FAILURE;
+    }
+    println!(
+        "Wrote a {}x{} picture of day {} to {}",
+        image.width(),
+        image.height(),
+        day,
+        output.display()
+    );
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_common::init_logging(cli.verbose);
+    match cli.command {
+        Command::List => {
+            for solution in days::SOLUTIONS {
+                let names = aoc_common::input_names(solution.day());
+                if names.is_empty() {
+                    println!("Day {:02}", solution.day());
+                } else {
+                    println!("Day {:02}  inputs: {}", solution.day(), names.join(", "));
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Run(args) => run(args),
+        Command::Verify {
+            day,
+            record,
+            answers,
+        } => verify(&day, record, answers),
+        Command::New { day } => new_day(day),
+        Command::Gen { day, seed, size } => gen(day, seed, size),
+        Command::Watch { day, part, inputs } => watch(day, part, inputs),
+        Command::Fetch {
+            day,
+            input_name,
+            base_url,
+        } => fetch(day, input_name, base_url),
+        Command::Submit(args) => submit(args),
+        Command::Render {
+            day,
+            input,
+            output,
+            scale,
+        } => render(day, input, output, scale),
+    }
+}
@@ -0,0 +1,823 @@
+use crate::alloc::{self, AllocStats, Bytes};
+use aoc_common::{format_result, Answer, InputSource, Part, Solution, SolveError, SolveResult};
+use serde::{Serialize, Serializer};
+use std::io::{self, Write};
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How the results of a run are printed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Readable text, grouped by day
+    #[default]
+    Text,
+    /// A JSON array with one record per part
+    Json,
+    /// A header row followed by one row per part
+    Csv,
+}
+
+/// How long something took, over one or more runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    /// Sorted from fastest to slowest, never empty
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    /// Runs `f` the given number of times, but at least once, keeping the first result
+    pub fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Timing) {
+        let mut first = None;
+        let mut samples = Vec::with_capacity(runs.max(1));
+        for _ in 0..runs.max(1) {
+            let start = Instant::now();
+            let result = f();
+            samples.push(start.elapsed());
+            first.get_or_insert(result);
+        }
+        (first.unwrap(), Timing::from_samples(samples))
+    }
+
+    /// Panics if there are no samples
+    pub fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        assert!(!samples.is_empty(), "a timing needs at least one sample");
+        samples.sort_unstable();
+        Timing { samples }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+}
+
+/// Everything that happened while solving one day
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    /// Which of the day's named inputs was solved, if it was one of them
+    pub input_name: Option<String>,
+    /// The puzzle input, or why it couldn't be read
+    pub input: Result<String, String>,
+    /// Parsing on its own, which both parts also do before solving
+    pub parse: Option<Timing>,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    /// Reads the day's input and solves the given parts,
+    /// timing parsing and each part over the given number of runs.
+    ///
+    /// When allocations are being counted, each part's first run is counted.
+    pub fn solve(
+        solution: &dyn Solution,
+        source: &InputSource,
+        parts: &[Part],
+        runs: usize,
+    ) -> DayRun {
+        let day = solution.day();
+        let input_name = source.name().map(str::to_string);
+        let input = match source.read(day) {
+            Ok(input) => input,
+            Err(e) => {
+                return DayRun {
+                    day,
+                    input_name,
+                    input: Err(e.to_string()),
+                    parse: None,
+                    parts: Vec::new(),
+                }
+            }
+        };
+        let (_, parse) = Timing::measure(runs, || solution.parse(&input));
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let mut allocations = None;
+                let (result, timing) = Timing::measure(runs, || {
+                    let (result, stats) = alloc::measure(|| solution.solve(part, &input));
+                    allocations.get_or_insert(stats);
+                    result
+                });
+                PartRun {
+                    part,
+                    result,
+                    timing,
+                    allocations: allocations.filter(|_| alloc::is_counting()),
+                }
+            })
+            .collect();
+        DayRun {
+            day,
+            input_name,
+            input: Ok(input),
+            parse: Some(parse),
+            parts,
+        }
+    }
+
+    /// Solves several days like [`DayRun::solve`], each with its own input,
+    /// running up to `jobs` days at a time.
+    ///
+    /// The runs come back in the same order as `days`, however long each day took.
+    pub fn solve_all(
+        days: &[(&dyn Solution, InputSource)],
+        parts: &[Part],
+        runs: usize,
+        jobs: usize,
+    ) -> Vec<DayRun> {
+        let next = AtomicUsize::new(0);
+        let mut day_runs: Vec<Option<DayRun>> = days.iter().map(|_| None).collect();
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1)))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut solved = Vec::new();
+                        // Each worker takes the next day nobody has started on yet
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some((solution, source)) = days.get(index) else {
+                                break;
+                            };
+                            solved.push((index, DayRun::solve(*solution, source, parts, runs)));
+                        }
+                        solved
+                    })
+                })
+                .collect();
+            for worker in workers {
+                let solved = worker.join().unwrap_or_else(|e| panic::resume_unwind(e));
+                for (index, day_run) in solved {
+                    day_runs[index] = Some(day_run);
+                }
+            }
+        });
+        day_runs.into_iter().map(Option::unwrap).collect()
+    }
+
+    /// Whether the input was missing or invalid
+    pub fn failed(&self) -> bool {
+        self.input.is_err()
+            || self
+                .parts
+                .iter()
+                .any(|part| matches!(part.result, Err(SolveError::Parse(_))))
+    }
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub result: SolveResult,
+    pub timing: Timing,
+    /// What the first run allocated, if allocations were counted
+    pub allocations: Option<AllocStats>,
+}
+
+/// The outcome of a single part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    InvalidInput,
+    MissingInput,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Unimplemented => "unimplemented",
+            Status::InvalidInput => "invalid_input",
+            Status::MissingInput => "missing_input",
+        }
+    }
+}
+
+/// One part of one day, as printed in the machine readable formats
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    /// The named input that was solved, left out for the day's default input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub part: u8,
+    pub status: Status,
+    #[serde(serialize_with = "serialize_answer")]
+    pub answer: Option<Answer>,
+    pub error: Option<String>,
+    pub duration_ms: Option<f64>,
+    /// Only there when allocations were counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocStats>,
+}
+
+/// Numbers are written as JSON numbers, everything else as strings
+fn serialize_answer<S: Serializer>(
+    answer: &Option<Answer>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match answer {
+        Some(Answer::Integer(n)) => serializer.serialize_i64(*n),
+        Some(Answer::BigInteger(n)) => serializer.serialize_i128(*n),
+        Some(answer) => serializer.serialize_str(&answer.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Flattens the runs into one record per day and part
+pub fn records(runs: &[DayRun], parts: &[Part]) -> Vec<Record> {
+    let mut records = Vec::new();
+    for run in runs {
+        if let Err(e) = &run.input {
+            records.extend(parts.iter().map(|part| Record {
+                day: run.day,
+                input: run.input_name.clone(),
+                part: part.number(),
+                status: Status::MissingInput,
+                answer: None,
+                error: Some(e.clone()),
+                duration_ms: None,
+                allocations: None,
+            }));
+            continue;
+        }
+        for part_run in &run.parts {
+            let (status, answer, error) = match &part_run.result {
+                Ok(answer) => (Status::Ok, Some(answer.clone()), None),
+                Err(SolveError::Unimplemented) => (Status::Unimplemented, None, None),
+                Err(e @ SolveError::Parse(_)) => (Status::InvalidInput, None, Some(e.to_string())),
+            };
+            records.push(Record {
+                day: run.day,
+                input: run.input_name.clone(),
+                part: part_run.part.number(),
+                status,
+                answer,
+                error,
+                duration_ms: Some(part_run.timing.median().as_secs_f64() * 1000.0),
+                allocations: part_run.allocations,
+            });
+        }
+    }
+    records
+}
+
+/// Writes the results in the given format.
+///
+/// The machine readable formats always include how long each part took,
+/// text only does when `timing` is set.
+pub fn write(
+    out: &mut impl Write,
+    format: Format,
+    runs: &[DayRun],
+    parts: &[Part],
+    timing: bool,
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            write_text(out, runs)?;
+            if timing {
+                write_timing(out, runs, parts)?;
+            }
+            write_allocations(out, runs)
+        }
+        Format::Json => write_json(out, &records(runs, parts)),
+        Format::Csv => write_csv(out, &records(runs, parts)),
+    }
+}
+
+fn write_text(out: &mut impl Write, runs: &[DayRun]) -> io::Result<()> {
+    let mut unimplemented = Vec::new();
+    for run in runs {
+        match &run.input_name {
+            Some(name) => writeln!(out, "Day {:02} ({})", run.day, name)?,
+            None => writeln!(out, "Day {:02}", run.day)?,
+        }
+        let input = match &run.input {
+            Ok(input) => input,
+            Err(e) => {
+                writeln!(out, "  Failed to read input: {}", e)?;
+                continue;
+            }
+        };
+        for part_run in &run.parts {
+            let result = format_result(part_run.part, &part_run.result, input);
+            writeln!(out, "  {}", result.replace('\n', "\n  "))?;
+            if let Err(SolveError::Unimplemented) = part_run.result {
+                unimplemented.push(format!("day {:02} part {}", run.day, part_run.part));
+            }
+        }
+    }
+
+    if !unimplemented.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "Unimplemented: {}", unimplemented.join(", "))?;
+    }
+    Ok(())
+}
+
+/// The median and the fastest time of something, `None` if there is nothing to show
+type TimingCell = Option<(Duration, Duration)>;
+
+fn add_cells(a: TimingCell, b: TimingCell) -> TimingCell {
+    match (a, b) {
+        (Some(a), Some(b)) => Some((a.0 + b.0, a.1 + b.1)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Writes a table with a row per day and a column per part, and the totals of all days
+fn write_timing(out: &mut impl Write, runs: &[DayRun], parts: &[Part]) -> io::Result<()> {
+    let Some(repeats) = runs
+        .iter()
+        .find_map(|run| run.parse.as_ref().map(Timing::runs))
+    else {
+        return Ok(());
+    };
+    let width = if repeats > 1 { 20 } else { 10 };
+    let format_row = |label: String, cells: &[TimingCell]| {
+        let mut row = format!("{:<4}", label);
+        for cell in cells {
+            let cell = match cell {
+                Some((median, min)) if repeats > 1 => format!("{:.1?} ({:.1?})", median, min),
+                Some((median, _)) => format!("{:.1?}", median),
+                None => "-".to_string(),
+            };
+            row.push_str(&format!(" | {:>width$}", cell));
+        }
+        row
+    };
+
+    writeln!(out)?;
+    if repeats > 1 {
+        writeln!(out, "Median time of {} runs, fastest in brackets", repeats)?;
+    }
+    let mut header = format!("{:<4}", "Day");
+    for column in ["Parse".to_string()]
+        .into_iter()
+        .chain(parts.iter().map(|part| format!("Part {}", part)))
+        .chain(["Total".to_string()])
+    {
+        header.push_str(&format!(" | {:>width$}", column));
+    }
+    writeln!(out, "{}", header)?;
+    writeln!(out, "{}", "-".repeat(header.len()))?;
+
+    // Parsing, each part and the total of the parts
+    let mut totals: Vec<TimingCell> = vec![None; parts.len() + 2];
+    for run in runs {
+        let Some(parse) = &run.parse else {
+            continue;
+        };
+        let mut cells = vec![Some((parse.median(), parse.min()))];
+        cells.extend(run.parts.iter().map(|part_run| {
+            // Parts that weren't solved took no time worth showing
+            part_run
+                .result
+                .is_ok()
+                .then(|| (part_run.timing.median(), part_run.timing.min()))
+        }));
+        cells.push(cells[1..].iter().copied().fold(None, add_cells));
+        for (total, &cell) in totals.iter_mut().zip(&cells) {
+            *total = add_cells(*total, cell);
+        }
+        writeln!(out, "{}", format_row(format!("{:02}", run.day), &cells))?;
+    }
+
+    writeln!(out, "{}", "-".repeat(header.len()))?;
+    writeln!(out, "{}", format_row("All".to_string(), &totals))
+}
+
+/// Writes a table with a row per day and part saying what it allocated, if allocations were counted
+fn write_allocations(out: &mut impl Write, runs: &[DayRun]) -> io::Result<()> {
+    let rows: Vec<(u8, Part, AllocStats)> = runs
+        .iter()
+        .flat_map(|run| {
+            let parts = run.parts.iter();
+            parts.filter_map(|part_run| Some((run.day, part_run.part, part_run.allocations?)))
+        })
+        .collect();
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out)?;
+    let header = format!(
+        "{:<4} | {:<4} | {:>11} | {:>11} | {:>11}",
+        "Day", "Part", "Allocations", "Allocated", "Peak"
+    );
+    writeln!(out, "{}", header)?;
+    writeln!(out, "{}", "-".repeat(header.len()))?;
+    for (day, part, stats) in rows {
+        writeln!(
+            out,
+            "{:<4} | {:<4} | {:>11} | {:>11} | {:>11}",
+            format!("{:02}", day),
+            part.number(),
+            stats.allocations,
+            Bytes(stats.bytes).to_string(),
+            Bytes(stats.peak_bytes).to_string()
+        )?;
+    }
+    Ok(())
+}
+
+fn write_json(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, records)?;
+    writeln!(out)
+}
+
+fn write_csv(out: &mut impl Write, records: &[Record]) -> io::Result<()> {
+    // Only runs over named inputs need to say which input each row is for,
+    // and only runs that counted allocations have them
+    let named = records.iter().any(|record| record.input.is_some());
+    let counted = records.iter().any(|record| record.allocations.is_some());
+    let mut header = vec!["day", "part", "status", "answer", "duration_ms"];
+    if named {
+        header.insert(1, "input");
+    }
+    if counted {
+        header.extend(["allocations", "allocated_bytes", "peak_bytes"]);
+    }
+    header.push("error");
+    writeln!(out, "{}", header.join(","))?;
+
+    for record in records {
+        let duration = record
+            .duration_ms
+            .map(|duration| format!("{:.3}", duration))
+            .unwrap_or_default();
+        write!(out, "{},", record.day)?;
+        if named {
+            write!(out, "{},", csv_field(input_label(record.input.as_deref())))?;
+        }
+        write!(
+            out,
+            "{},{},{},{},",
+            record.part,
+            record.status.as_str(),
+            csv_field(
+                &record
+                    .answer
+                    .as_ref()
+                    .map(Answer::to_string)
+                    .unwrap_or_default()
+            ),
+            duration,
+        )?;
+        if counted {
+            match record.allocations {
+                Some(stats) => write!(
+                    out,
+                    "{},{},{},",
+                    stats.allocations, stats.bytes, stats.peak_bytes
+                )?,
+                None => write!(out, ",,,")?,
+            }
+        }
+        writeln!(out, "{}", csv_field(record.error.as_deref().unwrap_or("")))?;
+    }
+    Ok(())
+}
+
+/// What an input is called in tables, where the day's default input has no name
+fn input_label(name: Option<&str>) -> &str {
+    name.unwrap_or("input.txt")
+}
+
+/// Writes a table with the answers for each input side by side,
+/// a row for each day and part and a column for each input.
+///
+/// Answers that take up several lines, like pictures, stay on their own lines.
+/// Why inputs were missing or invalid is listed after the table.
+pub fn write_side_by_side(out: &mut impl Write, runs: &[DayRun], parts: &[Part]) -> io::Result<()> {
+    let mut inputs: Vec<Option<&str>> = Vec::new();
+    let mut days: Vec<u8> = Vec::new();
+    for run in runs {
+        if !inputs.contains(&run.input_name.as_deref()) {
+            inputs.push(run.input_name.as_deref());
+        }
+        if !days.contains(&run.day) {
+            days.push(run.day);
+        }
+    }
+    // The default input first, then the named ones in alphabetical order
+    inputs.sort();
+
+    // Each row is a day and part, with the lines of the answer for each input
+    let mut rows: Vec<(u8, Part, Vec<Vec<String>>)> = Vec::new();
+    let mut problems = Vec::new();
+    for &day in &days {
+        for &part in parts {
+            let cells = inputs
+                .iter()
+                .map(|&input| {
+                    let Some(run) = runs
+                        .iter()
+                        .find(|run| run.day == day && run.input_name.as_deref() == input)
+                    else {
+                        return vec![];
+                    };
+                    let result = run.parts.iter().find(|part_run| part_run.part == part);
+                    match (&run.input, result.map(|part_run| &part_run.result)) {
+                        (Err(_), _) => vec!["missing input".to_string()],
+                        (Ok(_), Some(Ok(answer))) => {
+                            answer.to_string().lines().map(String::from).collect()
+                        }
+                        (Ok(_), Some(Err(SolveError::Parse(_)))) => {
+                            vec!["invalid input".to_string()]
+                        }
+                        (Ok(_), _) => vec!["-".to_string()],
+                    }
+                })
+                .collect();
+            rows.push((day, part, cells));
+        }
+        for run in runs.iter().filter(|run| run.day == day) {
+            let error = match &run.input {
+                Err(e) => Some(e.clone()),
+                Ok(_) => run
+                    .parts
+                    .iter()
+                    .find_map(|part_run| match &part_run.result {
+                        Err(e @ SolveError::Parse(_)) => Some(e.to_string()),
+                        _ => None,
+                    }),
+            };
+            if let Some(error) = error {
+                let label = input_label(run.input_name.as_deref());
+                problems.push(format!("Day {:02} {}: {}", day, label, error));
+            }
+        }
+    }
+
+    let widths: Vec<usize> = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, &input)| {
+            rows.iter()
+                .flat_map(|(_, _, cells)| cells[i].iter().map(|line| line.chars().count()))
+                .chain([input_label(input).chars().count()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let mut header = format!("{:<4} | {:<4}", "Day", "Part");
+    for (&input, width) in inputs.iter().zip(&widths) {
+        header.push_str(&format!(" | {:<width$}", input_label(input)));
+    }
+    writeln!(out, "{}", header)?;
+    writeln!(out, "{}", "-".repeat(header.len()))?;
+    for (day, part, cells) in &rows {
+        let height = cells.iter().map(Vec::len).max().unwrap_or(0).max(1);
+        for line in 0..height {
+            let mut row = if line == 0 {
+                format!("{:<4} | {:<4}", format!("{:02}", day), part.number())
+            } else {
+                format!("{:<4} | {:<4}", "", "")
+            };
+            for (cell, width) in cells.iter().zip(&widths) {
+                row.push_str(&format!(
+                    " | {:<width$}",
+                    cell.get(line).map_or("", String::as_str)
+                ));
+            }
+            writeln!(out, "{}", row.trim_end())?;
+        }
+    }
+
+    if !problems.is_empty() {
+        writeln!(out)?;
+        for problem in problems {
+            writeln!(out, "{}", problem)?;
+        }
+    }
+    Ok(())
+}
+
+/// Quotes a field if it contains anything that would break up the row, as in RFC 4180
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseError;
+
+    fn runs() -> Vec<DayRun> {
+        vec![
+            DayRun {
+                day: 13,
+                input_name: None,
+                input: Ok("6,10\n".to_string()),
+                parse: Some(Timing::from_samples(vec![Duration::from_micros(100)])),
+                parts: vec![
+                    PartRun {
+                        part: Part::One,
+                        result: Ok(Answer::Integer(17)),
+                        timing: Timing::from_samples(vec![Duration::from_micros(1500)]),
+                        allocations: None,
+                    },
+                    PartRun {
+                        part: Part::Two,
+                        result: Ok(Answer::Block("#.\n.#".to_string())),
+                        timing: Timing::from_samples(vec![Duration::from_micros(250)]),
+                        allocations: None,
+                    },
+                ],
+            },
+            DayRun {
+                day: 16,
+                input_name: None,
+                input: Ok("G".to_string()),
+                parse: Some(Timing::from_samples(vec![Duration::ZERO])),
+                parts: vec![
+                    PartRun {
+                        part: Part::One,
+                        result: Err(ParseError::new(1, 1, "bad, \"G\"").into()),
+                        timing: Timing::from_samples(vec![Duration::ZERO]),
+                        allocations: None,
+                    },
+                    PartRun {
+                        part: Part::Two,
+                        result: Err(SolveError::Unimplemented),
+                        timing: Timing::from_samples(vec![Duration::ZERO]),
+                        allocations: None,
+                    },
+                ],
+            },
+            DayRun {
+                day: 17,
+                input_name: None,
+                input: Err("no such file".to_string()),
+                parse: None,
+                parts: Vec::new(),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        write(&mut out, format, &runs(), &Part::ALL, false).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn csv_has_a_row_per_part() {
+        let expected = "\
+day,part,status,answer,duration_ms,error
+13,1,ok,17,1.500,
+13,2,ok,\"#.
+.#\",0.250,
+16,1,invalid_input,,0.000,\"invalid input at line 1, column 1: bad, \"\"G\"\"\"
+16,2,unimplemented,,0.000,
+17,1,missing_input,,,no such file
+17,2,missing_input,,,no such file
+";
+        assert_eq!(render(Format::Csv), expected);
+    }
+
+    #[test]
+    fn json_has_a_record_per_part() {
+        let records: Vec<serde_json::Value> = serde_json::from_str(&render(Format::Json)).unwrap();
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[0]["answer"], 17);
+        assert_eq!(records[1]["answer"], "#.\n.#");
+        assert_eq!(records[1]["duration_ms"], 0.25);
+        assert_eq!(records[2]["status"], "invalid_input");
+        assert_eq!(records[3]["answer"], serde_json::Value::Null);
+        assert_eq!(records[5]["status"], "missing_input");
+    }
+
+    #[test]
+    fn timing_median_and_min() {
+        let ms = Duration::from_millis;
+        let odd = Timing::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((odd.median(), odd.min()), (ms(3), ms(1)));
+        let even = Timing::from_samples(vec![ms(4), ms(1), ms(8), ms(2)]);
+        assert_eq!((even.median(), even.min()), (ms(3), ms(1)));
+    }
+
+    #[test]
+    fn timing_table_skips_unsolved_parts() {
+        let mut out = Vec::new();
+        write(&mut out, Format::Text, &runs(), &Part::ALL, true).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let table: Vec<&str> = out
+            .lines()
+            .skip_while(|line| !line.starts_with("Day  |"))
+            .collect();
+        assert_eq!(
+            table,
+            [
+                "Day  |      Parse |     Part 1 |     Part 2 |      Total",
+                "--------------------------------------------------------",
+                "13   |    100.0µs |      1.5ms |    250.0µs |      1.8ms",
+                "16   |      0.0ns |          - |          - |          -",
+                "--------------------------------------------------------",
+                "All  |    100.0µs |      1.5ms |    250.0µs |      1.8ms",
+            ]
+        );
+    }
+
+    /// Takes longer for earlier days, so they finish last when run in parallel
+    struct Slow(u8);
+
+    impl Solution for Slow {
+        fn day(&self) -> u8 {
+            self.0
+        }
+
+        fn parse(&self, _input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &str) -> SolveResult {
+            thread::sleep(Duration::from_millis(5 * (8 - self.0 as u64)));
+            Ok(self.0.into())
+        }
+    }
+
+    #[test]
+    fn solve_all_keeps_the_days_in_order() {
+        let days: Vec<Slow> = (1..=8).map(Slow).collect();
+        let source = InputSource::from_arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"));
+        let days: Vec<(&dyn Solution, InputSource)> = days
+            .iter()
+            .map(|day| (day as &dyn Solution, source.clone()))
+            .collect();
+
+        let runs = DayRun::solve_all(&days, &[Part::One], 1, 3);
+        let answers: Vec<Answer> = runs
+            .iter()
+            .map(|run| run.parts[0].result.clone().unwrap())
+            .collect();
+        assert_eq!(answers, ["1", "2", "3", "4", "5", "6", "7", "8"]);
+    }
+
+    #[test]
+    fn side_by_side_has_a_column_per_input() {
+        let mut runs = runs();
+        runs.insert(
+            1,
+            DayRun {
+                day: 13,
+                input_name: Some("alice".to_string()),
+                input: Ok("0,0\n".to_string()),
+                parse: Some(Timing::from_samples(vec![Duration::ZERO])),
+                parts: vec![
+                    PartRun {
+                        part: Part::One,
+                        result: Ok(Answer::Integer(5)),
+                        timing: Timing::from_samples(vec![Duration::ZERO]),
+                        allocations: None,
+                    },
+                    PartRun {
+                        part: Part::Two,
+                        result: Ok(Answer::Block("###\n#.#\n###".to_string())),
+                        timing: Timing::from_samples(vec![Duration::ZERO]),
+                        allocations: None,
+                    },
+                ],
+            },
+        );
+        runs.truncate(3);
+
+        let mut out = Vec::new();
+        write_side_by_side(&mut out, &runs, &Part::ALL).unwrap();
+        let expected = "\
+Day  | Part | input.txt     | alice
+-----------------------------------
+13   | 1    | 17            | 5
+13   | 2    | #.            | ###
+     |      | .#            | #.#
+     |      |               | ###
+16   | 1    | invalid input |
+16   | 2    | -             |
+
+Day 16 input.txt: invalid input at line 1, column 1: bad, \"G\"
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::answers::with_path;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/lib.rs.template");
+const MAIN_RS: &str = include_str!("../templates/main.rs.template");
+
+/// The root of the workspace, where the day crates live
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Creates the crate of a new day under `root` and adds it to the workspace.
+///
+/// The crate starts out with both parts unsolved, a `main.rs` that runs it,
+/// an empty `input.txt` and an empty example, and is registered as a workspace
+/// member, as a dependency of `aoc` and in [`SOLUTIONS`](crate::days::SOLUTIONS).
+/// An input that was fetched before the crate existed is kept. Returns the files written.
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    let manifest = dir.join("Cargo.toml");
+    if manifest.exists() {
+        return Err(with_path(
+            &manifest,
+            io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} exists already", name),
+            ),
+        ));
+    }
+    let fill_in = |template: &str| {
+        template
+            .replace("{{day02}}", &format!("{:02}", day))
+            .replace("{{day}}", &day.to_string())
+    };
+
+    let mut written = Vec::new();
+    let mut write = |path: PathBuf, contents: &str| -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| with_path(parent, e))?;
+        }
+        fs::write(&path, contents).map_err(|e| with_path(&path, e))?;
+        written.push(path);
+        Ok(())
+    };
+    write(manifest, &fill_in(CARGO_TOML))?;
+    write(dir.join("src").join("lib.rs"), &fill_in(LIB_RS))?;
+    write(dir.join("src").join("main.rs"), &fill_in(MAIN_RS))?;
+    if !dir.join("input.txt").exists() {
+        write(dir.join("input.txt"), "")?;
+    }
+    write(dir.join("inputs").join("example.txt"), "")?;
+
+    let edits: [(PathBuf, Edit); 3] = [
+        (root.join("Cargo.toml"), add_member),
+        (root.join("aoc").join("Cargo.toml"), add_dependency),
+        (root.join("aoc").join("src").join("days.rs"), add_solution),
+    ];
+    for (path, edit) in edits {
+        let text = fs::read_to_string(&path).map_err(|e| with_path(&path, e))?;
+        write(path, &edit(&text, &name))?;
+    }
+    Ok(written)
+}
+
+/// Adds the day with the given crate name to a file
+type Edit = fn(&str, &str) -> String;
+
+/// Adds `name` to the `members` of the workspace manifest, keeping them sorted
+fn add_member(manifest: &str, name: &str) -> String {
+    let Some((before, rest)) = manifest.split_once("members = [") else {
+        return manifest.to_string();
+    };
+    let Some((members, after)) = rest.split_once(']') else {
+        return manifest.to_string();
+    };
+    let mut members: Vec<String> = members
+        .split(',')
+        .map(|member| member.trim().to_string())
+        .filter(|member| !member.is_empty())
+        .collect();
+    members.push(format!("\"{}\"", name));
+    members.sort();
+    members.dedup();
+    format!(
+        "{}members = [\n    {}\n]{}",
+        before,
+        members.join(",\n    "),
+        after
+    )
+}
+
+/// Adds the day crate to the dependencies of `aoc`
+fn add_dependency(manifest: &str, name: &str) -> String {
+    insert_line(
+        manifest,
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+        |line| line.starts_with("day"),
+    )
+}
+
+/// Adds the day's solution to [`SOLUTIONS`](crate::days::SOLUTIONS)
+fn add_solution(days: &str, name: &str) -> String {
+    let solution = format!("Day{}", &name["day".len()..]);
+    insert_line(days, &format!("    &{}::{},", name, solution), |line| {
+        line.starts_with("    &day")
+    })
+}
+
+/// Inserts `new` among the lines that are `listed`, which are sorted,
+/// right after the last of them that comes before it
+fn insert_line(text: &str, new: &str, listed: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&new) {
+        return text.to_string();
+    }
+    let first = lines.iter().position(|line| listed(line));
+    let after = lines
+        .iter()
+        .rposition(|line| listed(line) && *line < new)
+        .map(|index| index + 1);
+    if let Some(index) = after.or(first) {
+        lines.insert(index, new);
+    }
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_the_day_in_order() {
+        let manifest = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day16\"\n]\n";
+        assert_eq!(
+            add_member(manifest, "day09"),
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day09\",\n    \"day16\"\n]\n"
+        );
+
+        let days = "pub static SOLUTIONS: &[&dyn Solution] = &[\n    &day01::Day01,\n    &day16::Day16,\n];\n";
+        assert_eq!(
+            add_solution(days, "day17"),
+            "pub static SOLUTIONS: &[&dyn Solution] = &[\n    &day01::Day01,\n    &day16::Day16,\n    &day17::Day17,\n];\n"
+        );
+
+        let manifest = "[dependencies]\nclap = \"4\"\nday01 = { path = \"../day01\" }\nday16 = { path = \"../day16\" }\nrand = \"0.8\"\n";
+        assert_eq!(
+            add_dependency(manifest, "day09"),
+            "[dependencies]\nclap = \"4\"\nday01 = { path = \"../day01\" }\nday09 = { path = \"../day09\" }\nday16 = { path = \"../day16\" }\nrand = \"0.8\"\n"
+        );
+    }
+
+    #[test]
+    fn creates_the_crate_next_to_the_others() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        let workspace = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\"\n]\n";
+        fs::write(root.join("Cargo.toml"), workspace).unwrap();
+        let dependencies = "[dependencies]\nday01 = { path = \"../day01\" }\n";
+        fs::write(root.join("aoc").join("Cargo.toml"), dependencies).unwrap();
+        let days = "&[\n    &day01::Day01,\n];\n";
+        fs::write(root.join("aoc").join("src").join("days.rs"), days).unwrap();
+        fs::create_dir_all(root.join("day17")).unwrap();
+        fs::write(root.join("day17").join("input.txt"), "fetched\n").unwrap();
+
+        let written = new_day(&root, 17).unwrap();
+        assert_eq!(written.len(), 7, "{:?}", written);
+        let lib = fs::read_to_string(root.join("day17").join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day17;"));
+        assert!(lib.contains("    fn day(&self) -> u8 {\n        17\n"));
+        let main = fs::read_to_string(root.join("day17").join("src").join("main.rs")).unwrap();
+        assert!(main.contains("aoc_common::run(&day17::Day17);"));
+        let input = fs::read_to_string(root.join("day17").join("input.txt")).unwrap();
+        assert_eq!(input, "fetched\n", "the fetched input is kept");
+        let days = fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap();
+        assert!(days.contains("    &day17::Day17,\n"));
+
+        let e = new_day(&root, 17).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+//! A tiny HTTP server standing in for the Advent of Code website in tests
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// A request the stand-in received
+#[derive(Debug)]
+pub struct Request {
+    /// E.g. `GET /2021/day/7/input`
+    pub method_and_path: String,
+    headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Answers one request after another with the given responses, then stops
+pub struct StandIn {
+    base_url: String,
+    server: JoinHandle<Vec<Request>>,
+}
+
+impl StandIn {
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/2021", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                requests.push(read_request(&mut reader));
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stand-in\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        StandIn { base_url, server }
+    }
+
+    /// What to use in place of `https://adventofcode.com/2021`
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Waits for every response to be sent, and returns the requests that were received
+    pub fn requests(self) -> Vec<Request> {
+        self.server.join().unwrap()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let method_and_path = line
+        .trim_end()
+        .rsplit_once(' ')
+        .map_or(line.trim_end(), |(start, _version)| start)
+        .to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        let Some((key, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+        headers.push((key.to_string(), value.trim().to_string()));
+    }
+
+    let mut request = Request {
+        method_and_path,
+        headers,
+        body: String::new(),
+    };
+    let length = request
+        .header("Content-Length")
+        .map_or(0, |length| length.parse().unwrap());
+    reader
+        .take(length)
+        .read_to_string(&mut request.body)
+        .unwrap();
+    request
+}
@@ -0,0 +1,413 @@
+use crate::answers::with_path;
+use crate::fetch::{Client, FetchError};
+use aoc_common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long the website is assumed to want to wait after a wrong answer, when the page doesn't say
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// Which way a wrong answer was off, for the puzzles where the website says
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// What the website made of an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer is wrong, and no other answer is looked at for `wait`
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Duration,
+    },
+    /// The answer wasn't looked at, as the previous one was given too recently
+    TooRecent(Duration),
+    /// The part is solved already, or isn't unlocked yet
+    WrongLevel,
+}
+
+/// Reads the verdict from the page the website answers a submission with
+pub fn parse_verdict(page: &str) -> Option<Verdict> {
+    let text = article_text(page).to_lowercase();
+    if text.contains("that's the right answer") {
+        Some(Verdict::Correct)
+    } else if text.contains("that's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = text
+            .split_once("please wait ")
+            .and_then(|(_, rest)| parse_spelled_out_wait(rest))
+            .unwrap_or(DEFAULT_WAIT);
+        Some(Verdict::Incorrect { hint, wait })
+    } else if text.contains("you gave an answer too recently") {
+        let wait = text
+            .split_once(" left to wait")
+            .and_then(|(before, _)| before.rsplit_once("you have "))
+            .and_then(|(_, left)| parse_wait(left))
+            .unwrap_or(DEFAULT_WAIT);
+        Some(Verdict::TooRecent(wait))
+    } else if text.contains("you don't seem to be solving the right level") {
+        Some(Verdict::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// The text of the page's `<article>`, which holds the message, without any tags
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"));
+    // What follows `<article` is the rest of the opening tag
+    let (article, mut in_tag) = article.map_or((page, false), |(article, _)| (article, true));
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads waits like `one minute` or `5 minutes`
+fn parse_spelled_out_wait(text: &str) -> Option<Duration> {
+    let mut words = text.split_whitespace();
+    let count = match words.next()? {
+        "a" | "an" | "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        count => count.parse().ok()?,
+    };
+    let unit = match words.next()? {
+        unit if unit.starts_with("second") => 1,
+        unit if unit.starts_with("minute") => 60,
+        unit if unit.starts_with("hour") => 60 * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(count * unit))
+}
+
+/// Reads waits like `37s` or `1m 5s`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for part in text.split_whitespace() {
+        let (count, unit) = part.split_at(part.len().checked_sub(1)?);
+        let unit = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            _ => return None,
+        };
+        seconds += count.parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Writes a wait the way the website does, e.g. `1m 5s`
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{}s", seconds),
+        (minutes, seconds) => format!("{}m {}s", minutes, seconds),
+    }
+}
+
+/// Every answer that has been submitted, so none is sent twice.
+///
+/// Stored as TOML, with a table per day and part:
+///
+/// ```toml
+/// [day01.part1]
+/// accepted = "1154"
+/// wait_until = 1638338460
+///
+/// [[day01.part1.rejected]]
+/// answer = "1200"
+/// hint = "too-high"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(BTreeMap<String, BTreeMap<String, PartHistory>>);
+
+/// The answers submitted to one part of a day
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartHistory {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    accepted: Option<String>,
+    /// When answers may be sent again, in seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rejected: Vec<Rejected>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Rejected {
+    answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hint: Option<Hint>,
+}
+
+impl History {
+    /// Reads the history from `path`, starting out empty if it doesn't exist yet
+    pub fn load(path: &Path) -> io::Result<History> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(e) => return Err(with_path(path, e)),
+        };
+        toml::from_str(&text)
+            .map_err(|e| with_path(path, io::Error::new(io::ErrorKind::InvalidData, e)))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text = toml::to_string(self)
+            .map_err(|e| with_path(path, io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        fs::write(path, text).map_err(|e| with_path(path, e))
+    }
+
+    fn part(&mut self, day: u8, part: Part) -> &mut PartHistory {
+        self.0
+            .entry(format!("day{:02}", day))
+            .or_default()
+            .entry(format!("part{}", part))
+            .or_default()
+    }
+}
+
+impl PartHistory {
+    /// Why `answer` can't be right, if the answers rejected so far tell
+    fn ruled_out(&self, answer: &Answer) -> Option<Outcome> {
+        self.rejected.iter().find_map(|rejected| {
+            let by = Answer::parse(&rejected.answer);
+            if by == *answer {
+                return Some(Outcome::AlreadyRejected(rejected.hint));
+            }
+            let hint = rejected.hint?;
+            let out = match hint {
+                Hint::TooHigh => *answer > by,
+                Hint::TooLow => *answer < by,
+            };
+            out.then_some(Outcome::RuledOut { by, hint })
+        })
+    }
+
+    fn wait_left(&self, now: SystemTime) -> Option<Duration> {
+        let until = UNIX_EPOCH + Duration::from_secs(self.wait_until?);
+        until
+            .duration_since(now)
+            .ok()
+            .filter(|left| !left.is_zero())
+    }
+
+    fn wait(&mut self, now: SystemTime, wait: Duration) {
+        let until = (now + wait).duration_since(UNIX_EPOCH).unwrap_or_default();
+        // Rounded up, so the wait is never cut short
+        self.wait_until = Some(until.as_secs() + u64::from(until.subsec_nanos() > 0));
+    }
+}
+
+/// What came of [`submit`]ting an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer was sent, and this is what the website made of it
+    Submitted(Verdict),
+    /// The part was solved already with this answer, so nothing was sent
+    AlreadyAccepted(Answer),
+    /// The same answer was sent and rejected before
+    AlreadyRejected(Option<Hint>),
+    /// An earlier answer was too high or too low, and this one is off the same way
+    RuledOut { by: Answer, hint: Hint },
+    /// The website asked to wait before answering again, and the wait isn't over
+    Cooldown(Duration),
+}
+
+/// Sends an answer to the website, unless `history` shows it can't be right
+/// or the website asked to wait, and records what happened in `history`.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: SystemTime,
+) -> Result<Outcome, SubmitError> {
+    let past = history.part(day, part);
+    if let Some(accepted) = &past.accepted {
+        return Ok(Outcome::AlreadyAccepted(Answer::parse(accepted)));
+    }
+    if let Some(outcome) = past.ruled_out(answer) {
+        return Ok(outcome);
+    }
+    if let Some(left) = past.wait_left(now) {
+        return Ok(Outcome::Cooldown(left));
+    }
+
+    let page = client.post_answer(day, part, &answer.to_string())?;
+    let verdict = parse_verdict(&page).ok_or(SubmitError::UnknownPage)?;
+    match verdict {
+        Verdict::Correct => past.accepted = Some(answer.to_string()),
+        Verdict::Incorrect { hint, wait } => {
+            past.rejected.push(Rejected {
+                answer: answer.to_string(),
+                hint,
+            });
+            past.wait(now, wait);
+        }
+        Verdict::TooRecent(wait) => past.wait(now, wait),
+        Verdict::WrongLevel => {}
+    }
+    Ok(Outcome::Submitted(verdict))
+}
+
+/// Where the history of submitted answers is kept, `submissions.toml` at the root of the workspace.
+///
+/// Unlike `answers.toml` it isn't checked in, as it belongs to whoever is logged in.
+pub fn default_history_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("submissions.toml")
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Fetch(FetchError),
+    /// The website answered with a page that doesn't say whether the answer was right
+    UnknownPage,
+}
+
+impl From<FetchError> for SubmitError {
+    fn from(e: FetchError) -> Self {
+        SubmitError::Fetch(e)
+    }
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Fetch(e) => write!(f, "{}", e),
+            SubmitError::UnknownPage => {
+                write!(f, "the website's answer doesn't say whether it was right")
+            }
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::StandIn;
+
+    const TOO_HIGH: &str = "<!DOCTYPE html>\n<html><body><main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2021/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2021/day/1\">[Return to Day 1]</a></p></article>\n</main></body></html>";
+    const TOO_RECENT: &str = "<html><body><main>\n<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2021/day/1\">[Return to Day 1]</a></p></article>\n</main></body></html>";
+    const CORRECT: &str = "<html><body><main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the sleigh keys. <a href=\"/2021/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main></body></html>";
+    const WRONG_LEVEL: &str = "<html><body><main>\n<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2021/day/1\">[Return to Day 1]</a></p></article>\n</main></body></html>";
+
+    #[test]
+    fn reads_the_verdict_from_the_page() {
+        let minute = Duration::from_secs(60);
+        assert_eq!(
+            parse_verdict(TOO_HIGH),
+            Some(Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: minute
+            })
+        );
+        assert_eq!(
+            parse_verdict(TOO_RECENT),
+            Some(Verdict::TooRecent(Duration::from_secs(65)))
+        );
+        assert_eq!(parse_verdict(CORRECT), Some(Verdict::Correct));
+        assert_eq!(parse_verdict(WRONG_LEVEL), Some(Verdict::WrongLevel));
+        assert_eq!(parse_verdict("<html>Internal error</html>"), None);
+
+        let slower = TOO_HIGH.replace("wait one minute", "wait 5 minutes");
+        assert!(matches!(
+            parse_verdict(&slower),
+            Some(Verdict::Incorrect { wait, .. }) if wait == 5 * minute
+        ));
+    }
+
+    #[test]
+    fn only_sends_answers_that_could_be_right() {
+        let server = StandIn::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(server.base_url(), Some("53616c7465".to_string()));
+        let mut history = History::default();
+        let start = UNIX_EPOCH + Duration::from_secs(1_638_338_400);
+        let mut send =
+            |answer: i64, now| submit(&client, &mut history, 1, Part::One, &answer.into(), now);
+
+        assert_eq!(
+            send(1200, start).unwrap(),
+            Outcome::Submitted(Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Duration::from_secs(60)
+            })
+        );
+        assert_eq!(
+            send(1200, start).unwrap(),
+            Outcome::AlreadyRejected(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            send(1300, start).unwrap(),
+            Outcome::RuledOut {
+                by: Answer::Integer(1200),
+                hint: Hint::TooHigh
+            }
+        );
+        let later = start + Duration::from_secs(20);
+        assert_eq!(
+            send(1154, later).unwrap(),
+            Outcome::Cooldown(Duration::from_secs(40))
+        );
+        let later = start + Duration::from_secs(60);
+        assert_eq!(
+            send(1154, later).unwrap(),
+            Outcome::Submitted(Verdict::Correct)
+        );
+        assert_eq!(
+            send(1154, later).unwrap(),
+            Outcome::AlreadyAccepted(Answer::Integer(1154))
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method_and_path, "POST /2021/day/1/answer");
+        assert_eq!(requests[1].body, "level=1&answer=1154");
+
+        let text = toml::to_string(&history).unwrap();
+        assert_eq!(toml::from_str::<History>(&text).unwrap(), history);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::answers::InputHash;
+use aoc_common::{default_input_path, input_names, named_input_path};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Notices when the contents of a set of files change.
+///
+/// Files are polled rather than watched through the operating system,
+/// which is plenty for a handful of puzzle inputs.
+#[derive(Debug)]
+pub struct Watcher {
+    files: Vec<WatchedFile>,
+}
+
+#[derive(Debug)]
+struct WatchedFile {
+    path: PathBuf,
+    /// What the file looked like the last time it was checked, `None` if it couldn't be read
+    seen: Option<Snapshot>,
+    checked: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Snapshot {
+    modified: SystemTime,
+    len: u64,
+    hash: InputHash,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Watcher {
+        let files = paths
+            .into_iter()
+            .map(|path| WatchedFile {
+                path,
+                seen: None,
+                checked: false,
+            })
+            .collect();
+        Watcher { files }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|file| file.path.as_path())
+    }
+
+    /// The files whose contents changed since the last call, which is every file the first time.
+    ///
+    /// Saving a file without changing it doesn't count, and neither does
+    /// a file that was missing before and still is.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for file in &mut self.files {
+            let snapshot = snapshot(&file.path, file.seen);
+            let first = !std::mem::replace(&mut file.checked, true);
+            let differs = match (file.seen, snapshot) {
+                (Some(before), Some(after)) => before.hash != after.hash,
+                (None, None) => false,
+                _ => true,
+            };
+            if first || differs {
+                changed.push(file.path.clone());
+            }
+            file.seen = snapshot;
+        }
+        changed
+    }
+}
+
+/// Only reads the file again when its size or modification time changed
+fn snapshot(path: &Path, before: Option<Snapshot>) -> Option<Snapshot> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?;
+    let len = metadata.len();
+    if let Some(before) = before.filter(|b| b.modified == modified && b.len == len) {
+        return Some(before);
+    }
+    let hash = InputHash::of(&fs::read_to_string(path).ok()?);
+    Some(Snapshot {
+        modified,
+        len,
+        hash,
+    })
+}
+
+/// The day's `input.txt`, along with all of its named inputs
+pub fn default_paths(day: u8) -> Vec<PathBuf> {
+    let named = input_names(day)
+        .into_iter()
+        .map(|name| named_input_path(day, &name));
+    [default_input_path(day)].into_iter().chain(named).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_reports_changed_contents() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (input, missing) = (dir.join("input.txt"), dir.join("missing.txt"));
+        fs::write(&input, "1\n2\n").unwrap();
+
+        let mut watcher = Watcher::new(vec![input.clone(), missing.clone()]);
+        assert_eq!(watcher.changed(), [input.clone(), missing.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::write(&input, "1\n2\n").unwrap();
+        assert!(watcher.changed().is_empty(), "the contents are the same");
+
+        fs::write(&input, "1\n2\n3\n").unwrap();
+        assert_eq!(watcher.changed(), vec![input]);
+
+        fs::write(&missing, "").unwrap();
+        assert_eq!(watcher.changed(), [missing]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
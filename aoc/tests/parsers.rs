@@ -0,0 +1,237 @@
+//! Feeds random and mutated inputs to every day's parser, which has to either
+//! succeed or return an error, and never panic or get stuck. Inputs that parse
+//! are then solved and rendered, which has to finish without panicking too.
+//!
+//! Run with `cargo test -p aoc --test parsers`. Set `PROPTEST_CASES` to try more inputs.
+
+use aoc::days;
+use aoc_common::{InputSource, Part};
+use proptest::prelude::*;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Long enough for any day to parse, solve and render the inputs here, even in a debug build
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The characters puzzle inputs are made of, so random inputs get past the first check
+const INPUT_CHARS: &str = "[0-9a-zA-Z ,.=>|#\\-\n]";
+
+/// A change to an input, at a position given as a fraction of its length
+#[derive(Debug, Clone)]
+enum Mutation {
+    Insert(f64, char),
+    Replace(f64, char),
+    Delete(f64),
+    Truncate(f64),
+    /// Repeats the line at the position
+    DuplicateLine(f64),
+    /// Removes the line at the position
+    DeleteLine(f64),
+    /// Replaces the number at the position, or inserts one if there isn't any
+    Number(f64, String),
+}
+
+impl Mutation {
+    fn apply(&self, input: &mut String) {
+        let at = |fraction: f64| {
+            let mut index = (input.len() as f64 * fraction) as usize;
+            while !input.is_char_boundary(index) {
+                index -= 1;
+            }
+            index
+        };
+        match *self {
+            Mutation::Insert(position, c) => input.insert(at(position), c),
+            Mutation::Replace(position, c) => {
+                let index = at(position);
+                if index < input.len() {
+                    input.remove(index);
+                }
+                input.insert(index, c);
+            }
+            Mutation::Delete(position) => {
+                let index = at(position);
+                if index < input.len() {
+                    input.remove(index);
+                }
+            }
+            Mutation::Truncate(position) => input.truncate(at(position)),
+            Mutation::DuplicateLine(position) => {
+                let (start, end) = line_around(input, at(position));
+                let line = input[start..end].to_string();
+                input.insert_str(end, &line);
+            }
+            Mutation::DeleteLine(position) => {
+                let (start, end) = line_around(input, at(position));
+                input.replace_range(start..end, "");
+            }
+            Mutation::Number(position, ref number) => {
+                let index = at(position);
+                let is_digit = |c: char| c.is_ascii_digit();
+                let start = input[..index].trim_end_matches(is_digit).len();
+                let end = input.len() - input[index..].trim_start_matches(is_digit).len();
+                input.replace_range(start..end, number);
+            }
+        }
+    }
+}
+
+/// The start and end of the line `index` is on, including its line break
+fn line_around(input: &str, index: usize) -> (usize, usize) {
+    let start = input[..index].rfind('\n').map_or(0, |i| i + 1);
+    let end = input[index..]
+        .find('\n')
+        .map_or(input.len(), |i| index + i + 1);
+    (start, end)
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    let position = 0.0..1.0;
+    let c = prop_oneof![
+        4 => INPUT_CHARS.prop_map(|s| s.chars().next().unwrap()),
+        // Zero is often a digit that puzzles leave out
+        1 => Just('0'),
+        1 => any::<char>(),
+    ];
+    prop_oneof![
+        (position.clone(), c.clone()).prop_map(|(p, c)| Mutation::Insert(p, c)),
+        (position.clone(), c).prop_map(|(p, c)| Mutation::Replace(p, c)),
+        position.clone().prop_map(Mutation::Delete),
+        position.clone().prop_map(Mutation::Truncate),
+        position.clone().prop_map(Mutation::DuplicateLine),
+        position.clone().prop_map(Mutation::DeleteLine),
+        (position, number()).prop_map(|(p, n)| Mutation::Number(p, n)),
+    ]
+}
+
+/// Numbers that tend to break solvers: zero, ones too large for the usual integer types,
+/// and ones that fit but would make a grid or a loop far too big
+fn number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("0".to_string()),
+        "[0-9]{1,3}",
+        "[1-9][0-9]{4,9}",
+        "[1-9][0-9]{10,40}",
+        Just(u32::MAX.to_string()),
+        Just(i64::MAX.to_string()),
+    ]
+}
+
+/// Inputs pieced together from numbers and the separators and words puzzle inputs use
+fn structured_input() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![
+        4 => number(),
+        1 => "[0-9]{2,12}",
+        1 => "[01]{1,6}",
+        1 => "[0-9A-F]{1,30}",
+        1 => "[a-zA-Z]{1,3}",
+        4 => prop::sample::select(vec![
+            ",", " -> ", "\n", "\n\n", " ", " | ", "-", "start", "end",
+            "fold along x=", "fold along y=", "forward ", "down ", "up ",
+        ])
+        .prop_map(str::to_string),
+    ];
+    prop::collection::vec(piece, 0..60).prop_map(|pieces| pieces.concat())
+}
+
+/// The inputs of every day that has any, as `(day, input)`
+fn known_inputs() -> Vec<(u8, String)> {
+    let mut inputs = Vec::new();
+    for solution in days::SOLUTIONS {
+        let day = solution.day();
+        let sources = std::iter::once(InputSource::Default).chain(
+            aoc_common::input_names(day)
+                .into_iter()
+                .map(InputSource::Named),
+        );
+        for source in sources {
+            if let Ok(text) = source.read(day) {
+                inputs.push((day, text));
+            }
+        }
+    }
+    inputs
+}
+
+/// Parses `input` as the given day's, and if that works solves both parts and renders it.
+/// Fails if any of it panics, or if it takes too long altogether.
+fn check_day(day: u8, input: String) -> Result<(), TestCaseError> {
+    let solution = days::find(day).unwrap();
+    let (sender, receiver) = mpsc::channel();
+    let shown = format!("{:?}", input);
+    thread::spawn(move || {
+        let _ = sender.send("parsing");
+        if solution.parse(&input).is_ok() {
+            for part in Part::ALL {
+                let _ = sender.send(if part == Part::One {
+                    "solving part 1"
+                } else {
+                    "solving part 2"
+                });
+                let _ = solution.solve(part, &input);
+            }
+            let _ = sender.send("rendering");
+            let _ = solution.render(&input);
+        }
+        let _ = sender.send("done");
+    });
+    let deadline = Instant::now() + TIMEOUT;
+    let mut step = "starting";
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        match receiver.recv_timeout(left) {
+            Ok("done") => return Ok(()),
+            Ok(next) => step = next,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                return Err(TestCaseError::fail(format!(
+                    "day {:02} was still {} after {:?} for {}",
+                    day, step, TIMEOUT, shown
+                )))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(TestCaseError::fail(format!(
+                    "day {:02} panicked {} {}",
+                    day, step, shown
+                )))
+            }
+        }
+    }
+}
+
+fn random_input() -> impl Strategy<Value = String> {
+    prop::string::string_regex(&format!("{}{{0,200}}", INPUT_CHARS)).unwrap()
+}
+
+fn day() -> impl Strategy<Value = u8> {
+    prop::sample::select(days::SOLUTIONS.iter().map(|s| s.day()).collect::<Vec<_>>())
+}
+
+proptest! {
+    #[test]
+    fn random_inputs_never_panic(day in day(), input in random_input()) {
+        check_day(day, input)?;
+    }
+
+    #[test]
+    fn structured_inputs_never_panic(day in day(), input in structured_input()) {
+        check_day(day, input)?;
+    }
+
+    #[test]
+    fn arbitrary_text_never_panics(day in day(), input in any::<String>()) {
+        check_day(day, input)?;
+    }
+
+    #[test]
+    fn mutated_inputs_never_panic(
+        (day, input) in prop::sample::select(known_inputs()),
+        mutations in prop::collection::vec(mutation(), 1..8),
+    ) {
+        let mut input = input;
+        for mutation in &mutations {
+            mutation.apply(&mut input);
+        }
+        check_day(day, input)?;
+    }
+}
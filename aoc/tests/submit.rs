@@ -0,0 +1,34 @@
+//! Runs `aoc submit` on answers that are turned down before anything is sent.
+
+use std::process::Command;
+
+fn submit(answer: &str) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "--day", "13", "--part", "2", "--answer", answer])
+        .args(["--base-url", "http://127.0.0.1:9"])
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn empty_answers_are_not_submitted() {
+    let (success, stderr) = submit("  ");
+    assert!(!success);
+    assert!(
+        stderr.contains("Empty answer, nothing to submit"),
+        "{}",
+        stderr
+    );
+    assert!(!stderr.contains("picture"), "{}", stderr);
+}
+
+#[test]
+fn pictures_have_to_be_read_first() {
+    let (success, stderr) = submit("#..#\n#..#");
+    assert!(!success);
+    assert!(stderr.contains("The answer is a picture"), "{}", stderr);
+}
@@ -0,0 +1,21 @@
+//! Runs `aoc verify` against the checked in inputs and `answers.toml`,
+//! so a change to how inputs are read or hashed can't quietly stop it from checking anything.
+
+use std::process::Command;
+
+#[test]
+fn every_implemented_part_has_a_recorded_answer() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "aoc verify failed:\n{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!stdout.contains("no recorded answer"), "{}", stdout);
+    assert!(stdout.contains("Part 1: ok"), "{}", stdout);
+}
@@ -0,0 +1,95 @@
+//! Day 1: Sonar Sweep
+//!
+//! Counts how often the depth of the sea floor increases.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{Solution, SolveResult};
+
+/// Counts the measurements that are deeper than the one before
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let nums = parse_input(input)?;
+    Ok(compute_consecutive_increasing_windows(&nums, 1))
+}
+
+/// Counts the sums of three consecutive measurements that are deeper than the sum before
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let nums = parse_input(input)?;
+    Ok(compute_consecutive_increasing_windows(&nums, 3))
+}
+
+fn compute_consecutive_increasing_windows(nums: &[u32], window_size: usize) -> usize {
+    let sum = |window: &[u32]| window.iter().map(|&num| u64::from(num)).sum::<u64>();
+    let mut count = 0;
+    for i in window_size..nums.len() {
+        let previous_window = sum(&nums[i - window_size..=i - 1]);
+        let current_window = sum(&nums[i - window_size + 1..=i]);
+        if current_window > previous_window {
+            count += 1
+        }
+    }
+    count
+}
+
+/// Parses one depth measurement per line
+pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    parse::lines(input)
+        .map(|line| line.parse(line.text()))
+        .collect()
+}
+
+/// Sonar Sweep, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day01.part1(EXAMPLE).unwrap(), "7");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day01.part2(EXAMPLE).unwrap(), "5");
+    }
+
+    #[test]
+    fn deep_windows_dont_overflow() {
+        let input = "4000000000\n4000000000\n4000000000\n4000000001\n";
+        assert_eq!(Day01.part2(input).unwrap(), "1");
+    }
+}
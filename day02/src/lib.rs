@@ -0,0 +1,148 @@
+//! Day 2: Dive!
+//!
+//! Follows the planned course of the submarine.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{Solution, SolveResult};
+
+/// A single step of the planned course
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Moves ahead by the given distance
+    Forward(i32),
+    /// Decreases the depth, or the aim in part 2
+    Up(i32),
+    /// Increases the depth, or the aim in part 2
+    Down(i32),
+}
+
+/// The depth times the horizontal position, which only overflows for very long courses
+fn multiply(input: &str, depth: i128, horizontal_position: i128) -> Result<i128, ParseError> {
+    depth.checked_mul(horizontal_position).ok_or_else(|| {
+        ParseError::end_of_input(
+            input,
+            "expected a course short enough for the answer to fit in 128 bits",
+        )
+    })
+}
+
+/// Multiplies the horizontal position and the depth reached by following the commands
+pub fn solve_part1(input: &str) -> Result<i128, ParseError> {
+    let commands = parse_input(input)?;
+    let mut horizontal_position: i128 = 0;
+    let mut depth: i128 = 0;
+    for command in commands {
+        match command {
+            Command::Forward(distance) => horizontal_position += i128::from(distance),
+            Command::Up(distance) => depth -= i128::from(distance),
+            Command::Down(distance) => depth += i128::from(distance),
+        }
+    }
+    multiply(input, depth, horizontal_position)
+}
+
+/// Like [`solve_part1`], but up and down change the aim, which moving forward then follows
+pub fn solve_part2(input: &str) -> Result<i128, ParseError> {
+    let commands = parse_input(input)?;
+    let mut horizontal_position: i128 = 0;
+    let mut depth: i128 = 0;
+    let mut aim: i128 = 0;
+    for command in commands {
+        match command {
+            Command::Forward(distance) => {
+                horizontal_position += i128::from(distance);
+                depth += aim * i128::from(distance);
+            }
+            Command::Up(distance) => aim -= i128::from(distance),
+            Command::Down(distance) => aim += i128::from(distance),
+        }
+    }
+    multiply(input, depth, horizontal_position)
+}
+
+/// Parses one command per line, such as `forward 5`
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let mut split = line.text().split_whitespace();
+            let direction = split
+                .next()
+                .ok_or_else(|| line.error_at_end("expected a direction"))?;
+            let command: fn(i32) -> Command = match direction {
+                "forward" => Command::Forward,
+                "up" => Command::Up,
+                "down" => Command::Down,
+                _ => {
+                    return Err(line.error(direction, format!("unknown direction `{}`", direction)))
+                }
+            };
+            let distance = split
+                .next()
+                .ok_or_else(|| line.error_at_end("expected a distance"))?;
+            let distance = line.parse(distance)?;
+            if let Some(extra) = split.next() {
+                return Err(line.error(extra, "expected the end of the line"));
+            }
+            Ok(command(distance))
+        })
+        .collect()
+}
+
+/// Dive!, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day02.part1(EXAMPLE).unwrap(), "150");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day02.part2(EXAMPLE).unwrap(), "900");
+    }
+
+    #[test]
+    fn long_courses_dont_overflow() {
+        let input = "forward 65535\ndown 65535\nforward 65535\n";
+        assert_eq!(Day02.part1(input).unwrap(), "8589672450");
+        assert_eq!(Day02.part2(input).unwrap(), "562924184010750");
+
+        let input = "down 2147483647\nforward 2147483647\n".repeat(5000);
+        assert!(solve_part1(&input).is_ok());
+        let error = solve_part2(&input).unwrap_err();
+        assert_eq!(error.line(), 10001);
+    }
+}
@@ -0,0 +1,208 @@
+//! Day 3: Binary Diagnostic
+//!
+//! Decodes the power consumption and life support rating from the diagnostic report.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{Solution, SolveResult};
+use std::cmp::Ordering;
+
+/// The most bits a reading can have, so that multiplying two of them fits in a `u32`
+const MAX_BITS: usize = 16;
+
+/// Returns the lines of the diagnostic report, which are binary numbers of equal length
+pub fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut report: Vec<&str> = Vec::new();
+    for line in parse::lines(input) {
+        if let Some((column, c)) = line.chars().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(line.error_at(column, format!("expected `0` or `1`, found `{}`", c)));
+        }
+        if line.text().len() > MAX_BITS {
+            return Err(line.error_at(MAX_BITS + 1, format!("expected at most {} bits", MAX_BITS)));
+        }
+        if let Some(first) = report.first() {
+            if line.text().len() != first.len() {
+                return Err(line.error_at_end(format!(
+                    "expected {} bits like the first line, found {}",
+                    first.len(),
+                    line.text().len()
+                )));
+            }
+        }
+        report.push(line.text());
+    }
+    if report.is_empty() || report[0].is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected at least one reading",
+        ));
+    }
+    Ok(report)
+}
+
+/// Multiplies the gamma rate, made of the most common bits, by the epsilon rate,
+/// made of the least common ones.
+///
+/// Both bits being equally common in some position is an error,
+/// pointing at that position on the first line.
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    let report = parse_input(input)?;
+    let count = report[0].len();
+    let mut gamma = 0;
+    let mut epsilon = 0;
+    for i in 0..count {
+        let mut total_zeroes = 0;
+        let mut total_ones = 0;
+        for line in &report {
+            let c = &line[i..=i];
+            if c == "0" {
+                total_zeroes += 1;
+            } else if c == "1" {
+                total_ones += 1;
+            }
+        }
+
+        match total_zeroes.cmp(&total_ones) {
+            Ordering::Greater => {
+                gamma <<= 1;
+                epsilon = (epsilon << 1) | 1;
+            }
+            Ordering::Less => {
+                gamma = (gamma << 1) | 1;
+                epsilon <<= 1;
+            }
+            Ordering::Equal => {
+                return Err(ParseError::new(
+                    1,
+                    i + 1,
+                    format!(
+                        "expected one bit to be more common in this position, found {} of each",
+                        total_ones
+                    ),
+                ))
+            }
+        };
+    }
+    Ok(gamma * epsilon)
+}
+
+/// Multiplies the oxygen generator and CO2 scrubber ratings,
+/// found by filtering the readings one bit at a time
+pub fn solve_part2(input: &str) -> Result<u32, ParseError> {
+    let diagnostics: Vec<Vec<char>> = parse_input(input)?
+        .into_iter()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect();
+    let number_of_bits = diagnostics[0].len();
+
+    let oxygen_generator_rating = compute_rating(input, diagnostics.clone(), number_of_bits, '0')?;
+    let carbon_dioxide_scrubber_rating = compute_rating(input, diagnostics, number_of_bits, '1')?;
+    Ok(oxygen_generator_rating * carbon_dioxide_scrubber_rating)
+}
+
+/// Filters the readings of `input` one bit at a time, which is an error if none are left
+fn compute_rating(
+    input: &str,
+    mut diagnostics: Vec<Vec<char>>,
+    number_of_bits: usize,
+    significant_character: char,
+) -> Result<u32, ParseError> {
+    for bit_position in 0..number_of_bits {
+        if diagnostics.len() == 1 {
+            break;
+        }
+        let mut indices_with_zero = Vec::new();
+        let mut indices_with_one = Vec::new();
+
+        for (i, reading) in diagnostics.iter().enumerate() {
+            if reading[bit_position] == '0' {
+                indices_with_zero.push(i);
+            } else if reading[bit_position] == '1' {
+                indices_with_one.push(i);
+            }
+        }
+
+        if indices_with_one.len() >= indices_with_zero.len() {
+            // 1 is most common
+            diagnostics.retain(|c| c[bit_position] == significant_character);
+        } else {
+            // 0 is most common
+            diagnostics.retain(|c| c[bit_position] != significant_character);
+        }
+        if diagnostics.is_empty() {
+            return Err(ParseError::end_of_input(
+                input,
+                format!(
+                    "expected a reading to be left after filtering on bit {}",
+                    bit_position + 1
+                ),
+            ));
+        }
+    }
+
+    let reading = diagnostics[0].iter().cloned().collect::<String>();
+    Ok(u32::from_str_radix(reading.as_str(), 2).unwrap())
+}
+
+/// Binary Diagnostic, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day03.part1(EXAMPLE).unwrap(), "198");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day03.part2(EXAMPLE).unwrap(), "230");
+    }
+
+    #[test]
+    fn ties_and_running_out_of_readings_are_errors() {
+        let error = solve_part1("01\n10\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        let error = solve_part2("0\n0\n").unwrap_err();
+        assert_eq!(
+            error.message(),
+            "expected a reading to be left after filtering on bit 1"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Day 4: Giant Squid
+//!
+//! Plays bingo against a giant squid, to win or to lose on purpose.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Solution, SolveResult};
+use tracing::debug;
+
+/// A bingo board, which remembers the numbers marked on it
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Board {
+    rows: Vec<Vec<(u32, bool)>>,
+    had_bingo: bool,
+}
+
+impl Board {
+    /// Creates a board without any marked numbers from its rows, which should form a square
+    pub fn new(rows: Vec<Vec<u32>>) -> Board {
+        Board {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|number| (number, false)).collect())
+                .collect(),
+            had_bingo: false,
+        }
+    }
+
+    /// Whether all numbers in a row or a column are marked
+    pub fn has_bingo(&self) -> bool {
+        let num_rows = self.rows.len();
+        let num_cols = self.rows[0].len();
+        let mut column = Vec::new();
+        for i in 0..num_rows {
+            let row = &self.rows[i];
+            if row.iter().all(|&(_, marked)| marked) {
+                return true;
+            }
+
+            for j in 0..num_cols {
+                column.push(self.rows[j][i]);
+            }
+            if column.iter().all(|&(_, marked)| marked) {
+                return true;
+            }
+            column.clear();
+        }
+
+        false
+    }
+
+    /// Marks the number wherever it is on the board
+    pub fn mark_number(&mut self, value: u32) {
+        for row in &mut self.rows {
+            for (val, marked) in row {
+                if *val == value {
+                    *marked = true;
+                }
+            }
+        }
+    }
+
+    /// The sum of all numbers that are not marked
+    pub fn sum_unmarked(&self) -> u64 {
+        let mut sum = 0;
+        for row in &self.rows {
+            for &(val, marked) in row {
+                if marked {
+                    continue;
+                }
+                sum += u64::from(val);
+            }
+        }
+        sum
+    }
+}
+
+/// Boards must be square, since bingo is checked along both rows and columns
+fn check_square(board: &Board, last_line: &InputLine) -> Result<(), ParseError> {
+    let num_columns = board.rows[0].len();
+    if board.rows.len() != num_columns {
+        return Err(last_line.error_at_end(format!(
+            "expected a square board, found {} rows of {} numbers",
+            board.rows.len(),
+            num_columns
+        )));
+    }
+    Ok(())
+}
+
+/// Parses the numbers to draw, from the first line, and the boards that follow.
+///
+/// Boards are separated by blank lines.
+pub fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<Board>), ParseError> {
+    let mut sections = parse::sections(input);
+    let header = sections
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "expected the draw order"))?;
+    let first = &header[0];
+    let draw_order: Vec<u32> = first.comma_separated()?;
+    if let Some(line) = header.get(1) {
+        return Err(line.error_at(1, "expected a blank line after the draw order"));
+    }
+
+    let mut boards = Vec::new();
+    for section in sections {
+        let mut board = Board::default();
+        for line in &section {
+            let row: Vec<(u32, bool)> = line
+                .text()
+                .split_whitespace()
+                .map(|x| line.parse(x).map(|x| (x, false)))
+                .collect::<Result<_, _>>()?;
+            if let Some(first_row) = board.rows.first() {
+                if row.len() != first_row.len() {
+                    return Err(line.error_at_end(format!(
+                        "expected {} numbers like the first row of the board, found {}",
+                        first_row.len(),
+                        row.len()
+                    )));
+                }
+            }
+            board.rows.push(row);
+        }
+        check_square(&board, section.last().unwrap())?;
+        boards.push(board);
+    }
+    if boards.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected at least one board",
+        ));
+    }
+
+    Ok((draw_order, boards))
+}
+
+/// The sum of a board's unmarked numbers times the number that was drawn last,
+/// which doesn't fit in a `u64` for large enough numbers
+fn score(sum_unmarked: u64, number: u32) -> u128 {
+    u128::from(sum_unmarked) * u128::from(number)
+}
+
+/// The score of the first board to win, which is the sum of its unmarked numbers
+/// times the number that was drawn last
+pub fn solve_part1(input: &str) -> Result<u128, ParseError> {
+    let (draw_order, mut boards) = parse_input(input)?;
+    for num in draw_order {
+        for (i, board) in boards.iter_mut().enumerate() {
+            board.mark_number(num);
+            if board.has_bingo() {
+                let score = score(board.sum_unmarked(), num);
+                debug!(board = i, number = num, score, "board won");
+                return Ok(score);
+            }
+        }
+    }
+    Ok(0)
+}
+
+/// The score of the last board to win
+pub fn solve_part2(input: &str) -> Result<u128, ParseError> {
+    let (draw_order, mut boards) = parse_input(input)?;
+    let mut last_drawn_bingo_number = 0;
+    let mut last_bingo_board_index = 0;
+    for num in draw_order {
+        if boards.iter().all(|board| board.had_bingo) {
+            break;
+        }
+        for (i, board) in boards.iter_mut().enumerate() {
+            board.mark_number(num);
+            if board.has_bingo() && !board.had_bingo {
+                board.had_bingo = true;
+                debug!(board = i, number = num, "board won");
+                last_drawn_bingo_number = num;
+                last_bingo_board_index = i;
+            }
+        }
+    }
+    let bingo_board = &boards[last_bingo_board_index];
+    Ok(score(bingo_board.sum_unmarked(), last_drawn_bingo_number))
+}
+
+/// Giant Squid, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day04.part1(EXAMPLE).unwrap(), "4512");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day04.part2(EXAMPLE).unwrap(), "1924");
+    }
+
+    #[test]
+    fn scores_of_large_numbers_dont_overflow() {
+        let input = "\
+1,2,3
+
+1 2 3
+4294967295 4294967295 4294967295
+4294967295 4294967295 4294967295
+";
+        assert_eq!(solve_part1(input).unwrap(), 6 * 4294967295 * 3);
+    }
+}
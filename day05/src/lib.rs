@@ -0,0 +1,241 @@
+//! Day 5: Hydrothermal Venture
+//!
+//! Finds where lines of hydrothermal vents overlap.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Grid, Image, Point, Rgb, Solution, SolveError, SolveResult};
+
+/// A line of vents, which is horizontal, vertical or at exactly 45 degrees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    /// One end of the line
+    pub start: Point,
+    /// The other end of the line, which also has a vent
+    pub end: Point,
+}
+
+impl Line {
+    /// Whether the line is at 45 degrees, rather than horizontal or vertical
+    pub fn is_diagonal(&self) -> bool {
+        self.start.x != self.end.x && self.start.y != self.end.y
+    }
+
+    /// Every point the line covers, from the start to the end.
+    /// Diagonal lines are left out unless `include_diagonal` is set.
+    pub fn segments(&self, include_diagonal: bool) -> Vec<Point> {
+        if self.is_diagonal() && !include_diagonal {
+            return Vec::new();
+        }
+
+        // Lines are horizontal, vertical or at exactly 45 degrees,
+        // so every step moves at most one unit along each axis
+        let step = (self.end - self.start).signum();
+        let mut point = self.start;
+        let mut segments = vec![point];
+        while point != self.end {
+            point += step;
+            segments.push(point);
+        }
+        segments
+    }
+}
+
+/// The lines of vents, along with how many of them cover each point
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    grid: Grid<u8>,
+    vent_paths: Vec<Line>,
+}
+
+impl Board {
+    /// The lines of vents, in the order they were listed
+    pub fn vent_paths(&self) -> &[Line] {
+        &self.vent_paths
+    }
+
+    /// How many of the lines drawn so far cover each point, with `x` as the column.
+    /// Counts stop at 255.
+    pub fn overlaps(&self) -> &Grid<u8> {
+        &self.grid
+    }
+
+    /// Counts every line towards the points it covers
+    pub fn draw_lines(&mut self, include_diagonal: bool) {
+        for line in &self.vent_paths {
+            for point in line.segments(include_diagonal) {
+                self.grid[point] = self.grid[point].saturating_add(1);
+            }
+        }
+    }
+}
+
+/// The largest coordinate of a vent, which keeps the grid of overlaps to a sensible size.
+/// Puzzle inputs stay below 1000.
+const MAX_COORDINATE: u32 = 4095;
+
+fn parse_coordinate(line: &InputLine, token: &str) -> Result<i64, ParseError> {
+    let coordinate: u32 = line.parse(token)?;
+    if coordinate > MAX_COORDINATE {
+        return Err(line.error(
+            token,
+            format!(
+                "expected a coordinate up to {}, found {}",
+                MAX_COORDINATE, coordinate
+            ),
+        ));
+    }
+    Ok(coordinate.into())
+}
+
+fn parse_coordinates(line: &InputLine, part: &str) -> Result<Point, ParseError> {
+    let part = part.trim();
+    let (x, y) = part
+        .split_once(',')
+        .ok_or_else(|| line.error(part, format!("expected `x,y`, found `{}`", part)))?;
+    Ok(Point::new(
+        parse_coordinate(line, x)?,
+        parse_coordinate(line, y)?,
+    ))
+}
+
+fn parse_line(line: InputLine) -> Result<Line, ParseError> {
+    let (start, end) = line.split_once("->")?;
+    let start = parse_coordinates(&line, start)?;
+    let end = parse_coordinates(&line, end)?;
+    let (dx, dy) = (start.x.abs_diff(end.x), start.y.abs_diff(end.y));
+    if dx != 0 && dy != 0 && dx != dy {
+        return Err(line.error_at(
+            1,
+            "expected a horizontal, vertical or 45 degree diagonal line",
+        ));
+    }
+    Ok(Line { start, end })
+}
+
+/// Parses one line of vents per line, such as `0,9 -> 5,9`
+pub fn parse_input(input: &str) -> Result<Board, ParseError> {
+    let vent_paths: Vec<Line> = parse::lines(input)
+        .map(parse_line)
+        .collect::<Result<_, _>>()?;
+
+    let x1 = vent_paths.iter().map(|line| line.end.x).max().unwrap_or(0);
+    let x2 = vent_paths
+        .iter()
+        .map(|line| line.start.x)
+        .max()
+        .unwrap_or(0);
+    let max_x = std::cmp::max(x1, x2) as usize;
+
+    let y1 = vent_paths.iter().map(|line| line.end.y).max().unwrap_or(0);
+    let y2 = vent_paths
+        .iter()
+        .map(|line| line.start.y)
+        .max()
+        .unwrap_or(0);
+    let max_y = std::cmp::max(y1, y2) as usize;
+
+    let number_of_columns = max_x + 1usize;
+    let number_of_rows = max_y + 1;
+
+    let grid = Grid::filled(number_of_columns, number_of_rows, 0);
+
+    Ok(Board { grid, vent_paths })
+}
+
+fn solve(input: &str, include_diagonal: bool) -> Result<usize, ParseError> {
+    let mut board = parse_input(input)?;
+    board.draw_lines(include_diagonal);
+    Ok(board.grid.iter().filter(|&&value| value > 1).count())
+}
+
+/// Counts the points where at least two horizontal or vertical lines overlap
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    solve(input, false)
+}
+
+/// Counts the points where at least two lines overlap, including diagonal ones
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    solve(input, true)
+}
+
+/// Draws how many lines, including diagonal ones, cover each point.
+/// Points without vents are black, and the more lines overlap the brighter the point.
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    let mut board = parse_input(input)?;
+    board.draw_lines(true);
+    let most = board.overlaps().iter().copied().max().unwrap_or(0);
+    let cells = board.overlaps().map(|&count| match count {
+        0 => Rgb::BLACK,
+        _ => Rgb(110, 0, 0).mix(
+            Rgb(255, 230, 0),
+            (count - 1) as f64 / most.saturating_sub(1).max(1) as f64,
+        ),
+    });
+    Ok(Image::new(cells))
+}
+
+/// Hydrothermal Venture, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+
+    fn render(&self, input: &str) -> Result<Image, SolveError> {
+        Ok(render(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day05.part1(EXAMPLE).unwrap(), "5");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day05.part2(EXAMPLE).unwrap(), "12");
+    }
+
+    #[test]
+    fn huge_coordinates_are_an_error() {
+        let error = parse_input("0,0 -> 0,4000000000\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 10));
+    }
+
+    #[test]
+    fn counts_stop_at_255() {
+        assert_eq!(Day05.part1(&"0,0 -> 0,3\n".repeat(300)).unwrap(), "4");
+    }
+}
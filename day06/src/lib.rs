@@ -0,0 +1,86 @@
+//! Day 6: Lanternfish
+//!
+//! Counts a school of lanternfish that keeps growing exponentially.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{Solution, SolveResult};
+
+/// Parses a comma separated list of timers, and counts how many fish have each timer
+pub fn parse_input(input: &str) -> Result<[u128; 9], ParseError> {
+    let mut timers = [0; 9];
+    for line in parse::lines(input) {
+        for s in line.comma_separated_fields() {
+            let x: usize = line.parse(s)?;
+            if x >= timers.len() {
+                return Err(line.error(s, format!("expected a timer from 0 to 8, found {}", x)));
+            }
+            timers[x] += 1;
+        }
+    }
+    Ok(timers)
+}
+
+/// How many fish have each timer a day later
+pub fn step(d: [u128; 9]) -> [u128; 9] {
+    [d[1], d[2], d[3], d[4], d[5], d[6], d[7] + d[0], d[8], d[0]]
+}
+
+fn solve(input: &str, num_days: u16) -> Result<u128, ParseError> {
+    let mut timers = parse_input(input)?;
+    for _ in 1..=num_days {
+        timers = step(timers);
+    }
+    Ok(timers.iter().sum())
+}
+
+/// Counts the fish after 80 days
+pub fn solve_part1(input: &str) -> Result<u128, ParseError> {
+    solve(input, 80)
+}
+
+/// Counts the fish after 256 days
+pub fn solve_part2(input: &str) -> Result<u128, ParseError> {
+    solve(input, 256)
+}
+
+/// Lanternfish, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day06.part1(EXAMPLE).unwrap(), "5934");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day06.part2(EXAMPLE).unwrap(), "26984457539");
+    }
+}
@@ -0,0 +1,132 @@
+//! Day 7: The Treachery of Whales
+//!
+//! Lines up crab submarines using as little fuel as possible.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{Solution, SolveResult};
+
+/// Parses a comma separated list of horizontal positions
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut positions = Vec::new();
+    for line in parse::lines(input) {
+        let line_positions: Vec<u32> = line.comma_separated()?;
+        positions.extend(line_positions.into_iter().map(i64::from));
+    }
+    if positions.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected at least one position",
+        ));
+    }
+    Ok(positions)
+}
+
+/// The fuel needed to move every crab to `target_position`, when each step costs 1
+pub fn get_total_fuel_p1(positions: &[i64], target_position: i64) -> i64 {
+    positions
+        .iter()
+        .map(|&start_position| (target_position - start_position).abs())
+        .sum()
+}
+
+/// The fuel needed to move every crab to `target_position`,
+/// when each step costs 1 more than the one before
+pub fn get_total_fuel_p2(positions: &[i64], target_position: i64) -> i128 {
+    positions
+        .iter()
+        .map(|&start_position| {
+            let distance = i128::from((target_position - start_position).abs());
+            distance * (distance + 1) / 2
+        })
+        .sum()
+}
+
+/// The least fuel needed to line up, when each step costs 1.
+///
+/// That is at the median, since moving away from it brings more crabs further away than closer.
+pub fn solve_part1(input: &str) -> Result<i64, ParseError> {
+    let mut horizontal_positions = parse_input(input)?;
+    horizontal_positions.sort_unstable();
+    let median = horizontal_positions[horizontal_positions.len() / 2];
+    Ok(get_total_fuel_p1(&horizontal_positions, median))
+}
+
+/// The least fuel needed to line up, when each step costs 1 more than the one before.
+///
+/// Without rounding, that is within half a step of the mean position,
+/// so the best whole position is at most a step further away.
+pub fn solve_part2(input: &str) -> Result<i128, ParseError> {
+    let horizontal_positions = parse_input(input)?;
+    let mean = horizontal_positions.iter().sum::<i64>() / horizontal_positions.len() as i64;
+    let min_fuel = (mean - 1..=mean + 2)
+        .map(|target_position| get_total_fuel_p2(&horizontal_positions, target_position))
+        .min()
+        .unwrap();
+    Ok(min_fuel)
+}
+
+/// The Treachery of Whales, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day07.part1(EXAMPLE).unwrap(), "37");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day07.part2(EXAMPLE).unwrap(), "168");
+    }
+
+    #[test]
+    fn lining_up_at_the_median_and_around_the_mean_is_cheapest() {
+        // Cheapest at 2 when steps cost 1, and at 4 when they cost more and more
+        let positions = [0, 1, 2, 2, 15];
+        for target_position in 0..=15 {
+            assert!(get_total_fuel_p1(&positions, target_position) >= 16);
+            assert!(get_total_fuel_p2(&positions, target_position) >= 88);
+        }
+        assert_eq!(solve_part1("0,1,2,2,15").unwrap(), 16);
+        assert_eq!(solve_part2("0,1,2,2,15").unwrap(), 88);
+    }
+
+    #[test]
+    fn distant_crabs_dont_overflow() {
+        assert_eq!(
+            Day07.part2("0,0,0,65535,65535,65535").unwrap(),
+            "3221225472"
+        );
+        assert_eq!(
+            Day07.part2("0,4294967295,4294967295").unwrap(),
+            "6148914691236517205"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Day 8: Seven Segment Search
+//!
+//! Works out which digits scrambled seven segment displays are showing.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Solution, SolveResult};
+use std::collections::HashSet;
+
+/// The segments, `a` to `g`, that are lit on a display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentDisplay(HashSet<char>);
+
+impl SegmentDisplay {
+    fn parse(line: &InputLine, s: &str) -> Result<SegmentDisplay, ParseError> {
+        let column = line.column_of(s);
+        let mut segments = HashSet::new();
+        for (i, c) in s.chars().enumerate() {
+            if !('a'..='g').contains(&c) {
+                return Err(line.error_at(
+                    column + i,
+                    format!("expected a segment from `a` to `g`, found `{}`", c),
+                ));
+            }
+            segments.insert(c);
+        }
+        Ok(SegmentDisplay(segments))
+    }
+}
+
+/// The ten unique patterns seen on a display, followed by the four digits it shows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    initial_displays: Vec<SegmentDisplay>,
+    final_displays: Vec<SegmentDisplay>,
+}
+
+impl Entry {
+    fn parse(line: InputLine) -> Result<Entry, ParseError> {
+        let (initial, r#final) = line.split_once("|")?;
+        let initial_displays = Entry::parse_displays(&line, initial, 10)?;
+        let final_displays = Entry::parse_displays(&line, r#final, 4)?;
+
+        Ok(Entry {
+            initial_displays,
+            final_displays,
+        })
+    }
+
+    /// The ten unique signal patterns, one for each digit
+    pub fn initial_displays(&self) -> &[SegmentDisplay] {
+        &self.initial_displays
+    }
+
+    /// The four digits of the output value
+    pub fn final_displays(&self) -> &[SegmentDisplay] {
+        &self.final_displays
+    }
+
+    fn parse_displays(
+        line: &InputLine,
+        s: &str,
+        count: usize,
+    ) -> Result<Vec<SegmentDisplay>, ParseError> {
+        let displays = s
+            .split_whitespace()
+            .map(|display| SegmentDisplay::parse(line, display))
+            .collect::<Result<Vec<_>, _>>()?;
+        if displays.len() != count {
+            return Err(line.error(
+                s,
+                format!("expected {} displays, found {}", count, displays.len()),
+            ));
+        }
+        Ok(displays)
+    }
+}
+
+impl SegmentDisplay {
+    /// The segments that are lit
+    pub fn segments(&self) -> &HashSet<char> {
+        &self.0
+    }
+
+    /// How many segments are lit
+    pub fn num_segments(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Parses one entry per line, with `|` between the patterns and the output value
+pub fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse::lines(input).map(Entry::parse).collect()
+}
+
+/// Counts how often 1, 4, 7 or 8 appear in the output values,
+/// which are the digits with a unique number of segments
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let entries = parse_input(input)?;
+    let count = entries
+        .into_iter()
+        .map(|entry| {
+            entry
+                .final_displays
+                .iter()
+                .filter(|&x| {
+                    let segment_count = x.num_segments();
+                    segment_count == 2
+                        || segment_count == 3
+                        || segment_count == 4
+                        || segment_count == 7
+                })
+                .count()
+        })
+        .sum();
+    Ok(count)
+}
+
+/// Seven Segment Search, solved by [`solve_part1`]
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgeafbc fegbdc | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day08.part1(EXAMPLE).unwrap(), "26");
+    }
+}
@@ -0,0 +1,197 @@
+//! Day 9: Smoke Basin
+//!
+//! Finds the low points of a heightmap and the basins that flow into them.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Grid, Image, ParseError, Point, Rgb, Solution, SolveError, SolveResult};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/// A heightmap, from 0 up to 9
+pub type Matrix = Grid<u8>;
+
+/// Parses one row of digits per line
+pub fn parse_input(input: &str) -> Result<Matrix, ParseError> {
+    Grid::parse_digits(input)
+}
+
+/// How smoke flows through a heightmap
+pub trait Neighbors {
+    /// The points up, down, left and right of `point` that are on the map
+    fn get_neighbors(&self, point: Point) -> Vec<Point>;
+    /// Whether all of the neighbors of `point` are higher than it
+    fn is_lowpoint(&self, point: Point) -> bool;
+    /// The points that are lower than all of their neighbors
+    fn get_lowpoints(&self) -> Vec<Point>;
+    /// The low point that smoke at `point` flows down to, or `None` at a height of 9
+    /// and on a plateau that smoke can't flow down from
+    fn get_basin(&self, point: Point) -> Option<Point>;
+    /// The lowest neighbor of `point`
+    fn get_lowest_neighbor(&self, point: Point) -> Option<Point>;
+}
+
+impl Neighbors for Matrix {
+    fn get_neighbors(&self, point: Point) -> Vec<Point> {
+        point
+            .neighbors4()
+            .filter(|&neighbor| self.contains_point(neighbor))
+            .collect()
+    }
+
+    fn is_lowpoint(&self, point: Point) -> bool {
+        let neighbors = self.get_neighbors(point);
+        neighbors.iter().all(|&x| self[x] > self[point])
+    }
+
+    fn get_lowpoints(&self) -> Vec<Point> {
+        let mut result = Vec::new();
+
+        for current in self.points() {
+            if self.is_lowpoint(current) {
+                result.push(current);
+            }
+        }
+
+        result
+    }
+
+    fn get_basin(&self, point: Point) -> Option<Point> {
+        if *self.get_point(point)? == 9 {
+            return None;
+        }
+
+        // Smoke can move sideways across points of the same height, but not in circles
+        let mut visited = HashSet::new();
+        let mut current = point;
+        loop {
+            let neighbor = self.get_lowest_neighbor(current)?;
+            if self.is_lowpoint(neighbor) {
+                return Some(neighbor);
+            }
+            if !visited.insert(neighbor) {
+                return None;
+            }
+            current = neighbor;
+        }
+    }
+
+    fn get_lowest_neighbor(&self, point: Point) -> Option<Point> {
+        let neighbors = self.get_neighbors(point);
+        neighbors.into_iter().min_by_key(|&neighbor| self[neighbor])
+    }
+}
+
+/// Sums the risk levels of the low points, which are their heights plus 1
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    let rows = parse_input(input)?;
+    let lowpoints = rows.get_lowpoints();
+    Ok(lowpoints.into_iter().map(|x| rows[x] as u32 + 1).sum())
+}
+
+/// Every point that isn't a 9, grouped by the low point it flows down to
+pub fn basins(rows: &Matrix) -> HashMap<Point, Vec<Point>> {
+    let mut mapping = HashMap::<Point, Vec<Point>>::new();
+    for current in rows.points() {
+        let basin = rows.get_basin(current);
+        let basin = match basin {
+            Some(basin) => basin,
+            None => continue,
+        };
+        if let Some(entry) = mapping.get_mut(&basin) {
+            entry.push(current);
+        } else {
+            mapping.insert(basin, vec![current]);
+        }
+    }
+
+    mapping
+}
+
+/// Multiplies the sizes of the three largest basins
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let rows = parse_input(input)?;
+    let mut basins: Vec<Vec<Point>> = basins(&rows).into_values().collect();
+
+    if basins.len() < 3 {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected at least three basins",
+        ));
+    }
+    basins.sort_by_key(|b| Reverse(b.len()));
+
+    Ok(basins[0].len() * basins[1].len() * basins[2].len())
+}
+
+/// Draws each basin in its own colour, darker the higher it is, with the low points in white
+/// and the points with a height of 9 between the basins in dark grey
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    let rows = parse_input(input)?;
+    let mut basins: Vec<(Point, Vec<Point>)> = basins(&rows).into_iter().collect();
+    basins.sort_by_key(|(lowpoint, _)| (lowpoint.y, lowpoint.x));
+
+    let mut cells = Grid::filled(rows.width(), rows.height(), Rgb(40, 40, 40));
+    for (index, (lowpoint, points)) in basins.into_iter().enumerate() {
+        let colour = Rgb::distinct(index);
+        for point in points {
+            cells[point] = colour.mix(Rgb::BLACK, rows[point] as f64 / 12.0);
+        }
+        cells[lowpoint] = Rgb::WHITE;
+    }
+    Ok(Image::new(cells))
+}
+
+/// Smoke Basin, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+
+    fn render(&self, input: &str) -> Result<Image, SolveError> {
+        Ok(render(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day09.part1(EXAMPLE).unwrap(), "15");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day09.part2(EXAMPLE).unwrap(), "1134");
+    }
+
+    #[test]
+    fn plateaus_have_no_basin() {
+        let rows = parse_input("11\n11\n").unwrap();
+        assert!(basins(&rows).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+//! Day 10: Syntax Scoring
+//!
+//! Finds corrupted and incomplete lines of brackets in the navigation subsystem.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, ParseError};
+use aoc_common::{Solution, SolveResult};
+
+const BRACKETS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Parses one line of brackets per line, checking that there is nothing else
+pub fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            match line
+                .chars()
+                .find(|&(_, c)| !is_opening(c) && !is_closing(c))
+            {
+                Some((column, c)) => {
+                    Err(line.error_at(column, format!("expected a bracket, found `{}`", c)))
+                }
+                None => Ok(line.text()),
+            }
+        })
+        .collect()
+}
+
+fn is_opening(c: char) -> bool {
+    BRACKETS.iter().any(|(x, _)| *x == c)
+}
+
+fn is_closing(c: char) -> bool {
+    BRACKETS.iter().any(|(_, x)| *x == c)
+}
+
+/// Returns the first illegal character, if the line is corrupted
+pub fn is_corrupted(line: &str) -> Option<char> {
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        if is_opening(c) {
+            stack.push(c);
+        } else if is_closing(c) {
+            let actual_opening = match stack.pop() {
+                Some(opening) => opening,
+                None => continue,
+            };
+            let expected_opening = match c {
+                ')' => '(',
+                ']' => '[',
+                '}' => '{',
+                '>' => '<',
+                _ => unreachable!(),
+            };
+
+            if expected_opening != actual_opening {
+                return Some(c);
+            }
+        }
+    }
+    None
+}
+
+/// Returns the missing closing brackets, for the line
+pub fn complete(line: &str) -> String {
+    let mut stack = Vec::new();
+    let mut to_append = String::new();
+    for c in line.chars() {
+        if is_opening(c) {
+            stack.push(c);
+        } else if is_closing(c) {
+            let _ = stack.pop();
+        }
+    }
+
+    while let Some(opening) = stack.pop() {
+        let closing = match opening {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            '<' => '>',
+            _ => unreachable!(),
+        };
+        to_append.push(closing);
+    }
+    to_append
+}
+
+/// Sums the syntax error scores of the corrupted lines
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let lines = parse_input(input)?;
+    let mut sum = 0;
+    for line in lines {
+        if let Some(illegal) = is_corrupted(line) {
+            sum += match illegal {
+                ')' => 3,
+                ']' => 57,
+                '}' => 1197,
+                '>' => 25137,
+                _ => unreachable!(),
+            };
+        }
+    }
+    Ok(sum)
+}
+
+/// The middle score of completing each incomplete line.
+///
+/// A line missing so many brackets that its score doesn't fit in a `u64` is an error.
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let lines = parse_input(input)?;
+    let mut sums = Vec::new();
+    for (i, line) in lines.into_iter().enumerate() {
+        if is_corrupted(line).is_some() {
+            continue;
+        }
+        let missing = complete(line);
+
+        let mut sum: u64 = 0;
+
+        for c in missing.chars() {
+            let score = match c {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                '>' => 4,
+                _ => unreachable!(),
+            };
+            sum = sum
+                .checked_mul(5)
+                .and_then(|sum| sum.checked_add(score))
+                .ok_or_else(|| {
+                    ParseError::new(
+                        i + 1,
+                        line.chars().count() + 1,
+                        format!(
+                            "expected a completion score that fits in 64 bits, but {} chunks are left open",
+                            missing.len()
+                        ),
+                    )
+                })?;
+        }
+
+        sums.push(sum);
+    }
+    if sums.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected at least one incomplete line",
+        ));
+    }
+    sums.sort_unstable();
+    sums.reverse();
+    Ok(sums[sums.len() / 2])
+}
+
+/// Syntax Scoring, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day10.part1(EXAMPLE).unwrap(), "26397");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day10.part2(EXAMPLE).unwrap(), "288957");
+    }
+
+    #[test]
+    fn scores_too_large_are_an_error() {
+        let error = Day10.part2(&"(".repeat(30)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid input at line 1, column 31: expected a completion score \
+             that fits in 64 bits, but 30 chunks are left open"
+        );
+    }
+}
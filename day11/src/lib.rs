@@ -0,0 +1,287 @@
+//! Day 11: Dumbo Octopus
+//!
+//! Simulates a grid of octopuses that flash when they have enough energy.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Grid, Image, ParseError, Point, Rgb, Solution, SolveError, SolveResult};
+use std::collections::HashSet;
+
+/// An octopus, with an energy level that goes up by 1 every step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Octopus {
+    energy_level: u8,
+    position: Point,
+    already_flashed: bool,
+}
+
+impl Octopus {
+    /// The energy level, which is 0 right after the octopus flashed
+    pub fn energy_level(&self) -> u8 {
+        self.energy_level
+    }
+
+    /// Whether the octopus flashed during the last step
+    pub fn flashed(&self) -> bool {
+        self.already_flashed
+    }
+
+    // If possible, the octopus flashes and returns true
+    fn flash(&mut self) -> bool {
+        if self.energy_level <= 9 || self.already_flashed {
+            false
+        } else {
+            self.already_flashed = true;
+            true
+        }
+    }
+}
+
+/// The grid of octopuses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix(Grid<Octopus>);
+
+impl Matrix {
+    /// The octopuses, as they are after the last step
+    pub fn octopuses(&self) -> &Grid<Octopus> {
+        &self.0
+    }
+
+    fn get_neighbor_indices(&self, position: Point) -> Vec<Point> {
+        position
+            .neighbors8()
+            .filter(|&neighbor| self.0.contains_point(neighbor))
+            .collect()
+    }
+
+    fn get_octopus_mut(&mut self, position: Point) -> Option<&mut Octopus> {
+        self.0.get_point_mut(position)
+    }
+
+    /// Runs one step, and returns how many octopuses flashed
+    pub fn step(&mut self) -> u32 {
+        self.phase1();
+        let flashes = self.phase2();
+        self.phase3();
+        flashes
+    }
+
+    /// First, the energy level of each octopus increases by 1.
+    fn phase1(&mut self) {
+        for octopus in self.0.iter_mut() {
+            octopus.energy_level += 1;
+            octopus.already_flashed = false;
+        }
+    }
+
+    /// Then, any octopus with an energy level greater than 9 flashes.
+    /// This increases the energy level of all adjacent octopuses by 1,
+    /// including octopuses that are diagonally adjacent.
+    /// If this causes an octopus to have an energy level greater than 9, it also flashes.
+    /// This process continues as long as new octopuses
+    /// keep having their energy level increased beyond 9.
+    /// (An octopus can only flash at most once per step.)
+    fn phase2(&mut self) -> u32 {
+        let mut sum = 0;
+        loop {
+            let mut any_flashed = false;
+            for row_index in 0..self.0.height() {
+                for column_index in 0..self.0.width() {
+                    let octopus = &mut self.0[(row_index, column_index)];
+                    if octopus.energy_level <= 9 || octopus.already_flashed {
+                        continue;
+                    }
+
+                    // The octopus flashed!
+                    any_flashed = true;
+
+                    let position = octopus.position;
+                    sum += self.process_octopous(position);
+                }
+            }
+
+            if !any_flashed {
+                break;
+            }
+        }
+        sum
+    }
+
+    /// Finally, any octopus that flashed during this step
+    /// has its energy level set to 0, as it used all of its energy to flash.
+    fn phase3(&mut self) {
+        for octopus in self.0.iter_mut() {
+            if octopus.already_flashed {
+                octopus.energy_level = 0;
+            }
+        }
+    }
+
+    /// Flashes the octopus at the given position if it can, and then every neighbor
+    /// that the flashes push over 9 in turn.
+    /// Returns the total number of flashes, caused by the flash of the current octopus
+    fn process_octopous(&mut self, position: Point) -> u32 {
+        let mut sum = 0;
+        let mut flashing = vec![position];
+        while let Some(position) = flashing.pop() {
+            if !self.get_octopus_mut(position).is_some_and(Octopus::flash) {
+                continue;
+            }
+            sum += 1;
+
+            // Increase energy level of all neighbors by 1, and see if they flash too
+            for neighbor_position in self.get_neighbor_indices(position) {
+                let neighbor = self.get_octopus_mut(neighbor_position).unwrap();
+                neighbor.energy_level += 1;
+                flashing.push(neighbor_position);
+            }
+        }
+        sum
+    }
+}
+
+/// Parses one row of energy levels per line
+pub fn parse_input(input: &str) -> Result<Matrix, ParseError> {
+    let energy_levels = Grid::parse_digits(input)?;
+    let octopuses = Grid::from_fn(
+        energy_levels.width(),
+        energy_levels.height(),
+        |row_index, column_index| Octopus {
+            energy_level: energy_levels[(row_index, column_index)],
+            position: Point::from_grid_index((row_index, column_index)),
+            already_flashed: false,
+        },
+    );
+    Ok(Matrix(octopuses))
+}
+
+/// Counts the flashes in the first 100 steps
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    let mut matrix = parse_input(input)?;
+    let mut num_flashes = 0;
+    for _ in 1..=100 {
+        num_flashes += matrix.step();
+    }
+    Ok(num_flashes)
+}
+
+/// The first step during which every octopus flashes.
+///
+/// It's an error if the octopuses get back to energy levels they had before without that
+/// happening, since from there on they repeat themselves and never all flash at once.
+pub fn solve_part2(input: &str) -> Result<u32, ParseError> {
+    let mut matrix = parse_input(input)?;
+    let num_octopuses = matrix.0.len() as u32;
+    let mut seen = HashSet::new();
+    let mut step = 0;
+    loop {
+        step += 1;
+        let num_flashes = matrix.step();
+        if num_flashes == num_octopuses {
+            return Ok(step);
+        }
+        let energy_levels: Vec<u8> = matrix.0.iter().map(Octopus::energy_level).collect();
+        if !seen.insert(energy_levels) {
+            return Err(ParseError::end_of_input(
+                input,
+                format!(
+                    "expected every octopus to flash at once, but they repeat themselves from step {}",
+                    step
+                ),
+            ));
+        }
+    }
+}
+
+/// Draws the energy levels after each of the first 100 steps, ten steps to a row.
+/// Octopuses that flashed are white, and the others get brighter as their energy goes up.
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    const FRAMES_PER_ROW: usize = 10;
+    const GAP: usize = 1;
+
+    let mut matrix = parse_input(input)?;
+    let (width, height) = (matrix.0.width(), matrix.0.height());
+    let columns = FRAMES_PER_ROW * (width + GAP) - GAP;
+    let rows = FRAMES_PER_ROW * (height + GAP) - GAP;
+    let mut cells = Grid::filled(columns, rows, Rgb(90, 90, 90));
+    for frame in 0..FRAMES_PER_ROW * FRAMES_PER_ROW {
+        matrix.step();
+        let top = frame / FRAMES_PER_ROW * (height + GAP);
+        let left = frame % FRAMES_PER_ROW * (width + GAP);
+        for ((row, column), octopus) in matrix.0.indexed_iter() {
+            cells[(top + row, left + column)] = if octopus.flashed() {
+                Rgb::WHITE
+            } else {
+                Rgb(5, 5, 40).mix(Rgb(40, 120, 255), octopus.energy_level() as f64 / 9.0)
+            };
+        }
+    }
+    Ok(Image::new(cells))
+}
+
+/// Dumbo Octopus, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+
+    fn render(&self, input: &str) -> Result<Image, SolveError> {
+        Ok(render(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11.part1(EXAMPLE).unwrap(), "1656");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day11.part2(EXAMPLE).unwrap(), "195");
+    }
+
+    #[test]
+    fn octopuses_that_never_synchronise_are_an_error() {
+        assert!(solve_part2("3322874652\n5636588857\n").is_err());
+    }
+
+    #[test]
+    fn large_grids_flash_without_running_out_of_stack() {
+        let row = "9".repeat(300);
+        let input = format!("{}\n", row).repeat(300);
+        assert_eq!(solve_part2(&input).unwrap(), 1);
+        // From then on, they all flash together every 10 steps
+        assert_eq!(solve_part1(&input).unwrap(), 10 * 300 * 300);
+    }
+}
@@ -0,0 +1,205 @@
+//! Day 12: Passage Pathing
+//!
+//! Counts the paths through a cave system that visit small caves a limited number of times.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Solution, SolveResult};
+use aoc_graph::{Graph, NodeId};
+use tracing::debug;
+
+fn parse_cave<'a>(line: &InputLine, cave: &'a str) -> Result<&'a str, ParseError> {
+    let is_small = cave.chars().all(|c| c.is_ascii_lowercase());
+    let is_big = cave.chars().all(|c| c.is_ascii_uppercase());
+    if cave.is_empty() || !(is_small || is_big) {
+        return Err(line.error(
+            cave,
+            format!(
+                "expected an all lower or all upper case cave name, found `{}`",
+                cave
+            ),
+        ));
+    }
+    Ok(cave)
+}
+
+/// Parses one connection per line, such as `start-A`
+pub fn parse_input<'a>(input: &'a str) -> Result<Visitor<'a>, ParseError> {
+    let mut caves = Graph::new();
+    for line in parse::lines(input) {
+        let (start, end) = line.split_once("-")?;
+        let start = parse_cave(&line, start)?;
+        let end = parse_cave(&line, end)?;
+        if !Visitor::is_small_cave(start) && !Visitor::is_small_cave(end) {
+            // Paths could bounce between the two caves forever
+            return Err(line.error_at(1, "two big caves can't be connected"));
+        }
+        caves.add_undirected_edge(start, end, ());
+    }
+    let start = caves
+        .id(&"start")
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a connection to `start`"))?;
+    // Without a connection to `end` there are no paths, rather than an error
+    let end = caves.add_node("end");
+    let small = caves
+        .nodes()
+        .map(|id| Visitor::is_small_cave(caves.name(id)))
+        .collect();
+    Ok(Visitor {
+        caves,
+        small,
+        start,
+        end,
+    })
+}
+
+/// The cave system, with a connection each way between connected caves
+#[derive(Debug, Clone)]
+pub struct Visitor<'a> {
+    caves: Graph<&'a str>,
+    /// Whether each cave is small, by node index
+    small: Vec<bool>,
+    start: NodeId,
+    end: NodeId,
+}
+
+impl<'a> Visitor<'a> {
+    /// The caves, named as in the input
+    pub fn caves(&self) -> &Graph<&'a str> {
+        &self.caves
+    }
+
+    /// Whether the cave is small, which is when its name is lower case
+    pub fn is_small_cave(cave: &str) -> bool {
+        cave.chars().all(|c| c.is_lowercase())
+    }
+
+    /// The paths from `start` to `end` that visit small caves at most once
+    pub fn paths(&self) -> Vec<Vec<&'a str>> {
+        self.find_paths(|path, cave| !self.small[cave.index()] || !path.contains(&cave))
+    }
+
+    /// The paths from `start` to `end` that visit a single small cave twice,
+    /// other than `start`, and the other small caves at most once
+    pub fn paths_visiting_a_small_cave_twice(&self) -> Vec<Vec<&'a str>> {
+        self.find_paths(|path, cave| {
+            if cave == self.start {
+                return false;
+            }
+            if !self.small[cave.index()] || !path.contains(&cave) {
+                return true;
+            }
+            let visited_twice = path
+                .iter()
+                .enumerate()
+                .any(|(i, c)| self.small[c.index()] && path[..i].contains(c));
+            !visited_twice
+        })
+    }
+
+    fn find_paths(&self, can_enter: impl FnMut(&[NodeId], NodeId) -> bool) -> Vec<Vec<&'a str>> {
+        let paths = self.caves.all_paths(self.start, self.end, can_enter);
+        debug!(paths = paths.len(), "paths found");
+        paths
+            .iter()
+            .map(|path| self.caves.names(path).into_iter().copied().collect())
+            .collect()
+    }
+}
+
+/// Counts the paths that visit small caves at most once
+pub fn solve_part1(input: &str) -> Result<u32, ParseError> {
+    let visitor = parse_input(input)?;
+    Ok(visitor.paths().len() as u32)
+}
+
+/// Counts the paths that visit a single small cave twice and the others at most once
+pub fn solve_part2(input: &str) -> Result<u32, ParseError> {
+    let visitor = parse_input(input)?;
+    Ok(visitor.paths_visiting_a_small_cave_twice().len() as u32)
+}
+
+/// Passage Pathing, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+";
+
+    const MEDIUM_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const LARGE_EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day12.part1(SMALL_EXAMPLE).unwrap(), "10");
+        assert_eq!(Day12.part1(MEDIUM_EXAMPLE).unwrap(), "19");
+        assert_eq!(Day12.part1(LARGE_EXAMPLE).unwrap(), "226");
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(Day12.part2(SMALL_EXAMPLE).unwrap(), "36");
+        assert_eq!(Day12.part2(MEDIUM_EXAMPLE).unwrap(), "103");
+        assert_eq!(Day12.part2(LARGE_EXAMPLE).unwrap(), "3509");
+    }
+}
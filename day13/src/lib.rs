@@ -0,0 +1,305 @@
+//! Day 13: Transparent Origami
+//!
+//! Folds a sheet of transparent paper with dots on it until it shows a code.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Answer, Grid, Image, Point, Rgb, Solution, SolveError, SolveResult};
+use std::{collections::HashSet, fmt::Debug};
+use tracing::debug;
+
+/// A fold of the paper along a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fold {
+    /// The axis the line is on
+    pub axis: Axis,
+    /// Where the line crosses the axis
+    pub index: i64,
+}
+
+/// The axis a fold line is on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// A vertical line, which folds the right half over to the left
+    X,
+    /// A horizontal line, which folds the bottom half up
+    Y,
+}
+
+/// The sheet of paper
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    dots: HashSet<Point>,
+}
+
+impl Matrix {
+    /// Where the dots are, with overlapping dots counted once
+    pub fn dots(&self) -> &HashSet<Point> {
+        &self.dots
+    }
+
+    /// The part of the paper that has dots on it, with `true` where there is a dot
+    pub fn paper(&self) -> Grid<bool> {
+        let max_x = self.dots.iter().map(|dot| dot.x).max().unwrap() + 1;
+        let max_y = self.dots.iter().map(|dot| dot.y).max().unwrap() + 1;
+        let mut paper = Grid::filled(max_x as usize, max_y as usize, false);
+        for &dot in self.dots.iter() {
+            paper[dot] = true;
+        }
+        paper
+    }
+
+    /// Draws the dots as `#` and the rest of the paper as `.`
+    pub fn render(&self) -> String {
+        self.paper()
+            .map(|&dot| if dot { '#' } else { '.' })
+            .to_string()
+    }
+
+    /// Folds the paper along a line
+    pub fn apply_fold(&mut self, fold: Fold) {
+        self.fold_quietly(fold);
+        debug!(axis = ?fold.axis, index = fold.index, dots = self.dots.len(), "fold applied");
+    }
+
+    /// Folds the paper without logging it, for checking the folds while parsing
+    fn fold_quietly(&mut self, fold: Fold) {
+        match fold.axis {
+            Axis::X => self.apply_vertical_fold(fold),
+            Axis::Y => self.apply_horizontal_fold(fold),
+        }
+    }
+
+    fn apply_vertical_fold(&mut self, fold: Fold) {
+        let mut result = HashSet::new();
+        for dot in self.dots.iter() {
+            if dot.x > fold.index {
+                let distance = dot.x - fold.index;
+                let x = fold.index - distance;
+                result.insert(Point::new(x, dot.y));
+            } else {
+                result.insert(*dot);
+            }
+        }
+        self.dots = result;
+    }
+
+    fn apply_horizontal_fold(&mut self, fold: Fold) {
+        let mut result = HashSet::new();
+        for dot in self.dots.iter() {
+            if dot.y > fold.index {
+                let distance = dot.y - fold.index;
+                let y = fold.index - distance;
+                result.insert(Point::new(dot.x, y));
+            } else {
+                result.insert(*dot);
+            }
+        }
+        self.dots = result;
+    }
+}
+
+/// The largest coordinate of a dot, which keeps the paper to a sensible size.
+/// Puzzle inputs stay below 1500.
+const MAX_COORDINATE: u32 = 4095;
+
+fn parse_coordinate(line: &InputLine, token: &str) -> Result<i64, ParseError> {
+    let coordinate: u32 = line.parse(token)?;
+    if coordinate > MAX_COORDINATE {
+        return Err(line.error(
+            token,
+            format!(
+                "expected a coordinate up to {}, found {}",
+                MAX_COORDINATE, coordinate
+            ),
+        ));
+    }
+    Ok(coordinate.into())
+}
+
+/// Parses the dots, one `x,y` pair per line, then a blank line and the folds.
+///
+/// Every fold has to be at least halfway across the paper,
+/// so that no dot is folded past the top or left edge.
+pub fn parse_input(input: &str) -> Result<(Matrix, Vec<Fold>), ParseError> {
+    let mut sections = parse::sections(input);
+
+    let mut dots = HashSet::new();
+    let mut folds = Vec::new();
+
+    for line in sections.next().unwrap_or_default() {
+        let (x, y) = line.split_once(",")?;
+        let (x, y) = (parse_coordinate(&line, x)?, parse_coordinate(&line, y)?);
+        dots.insert(Point::new(x, y));
+    }
+
+    // Folded along the way, to check that each fold is far enough across
+    let mut folded = Matrix { dots: dots.clone() };
+
+    for line in sections.flatten() {
+        let instruction = line
+            .text()
+            .strip_prefix("fold along ")
+            .ok_or_else(|| line.error_at(1, "expected `fold along`"))?;
+        let (axis_name, index) = instruction
+            .split_once('=')
+            .ok_or_else(|| line.error_at_end("expected `=`"))?;
+        let axis = match axis_name {
+            "x" => Axis::X,
+            "y" => Axis::Y,
+            _ => return Err(line.error(axis_name, "expected the `x` or `y` axis")),
+        };
+        let index_token = index;
+        let index: i64 = line.parse::<u32>(index)?.into();
+        let max = folded
+            .dots
+            .iter()
+            .map(|dot| match axis {
+                Axis::X => dot.x,
+                Axis::Y => dot.y,
+            })
+            .max()
+            .unwrap_or(0);
+        if max > 2 * index {
+            return Err(line.error(
+                index_token,
+                format!(
+                    "expected a fold at least halfway across the paper, which reaches {}",
+                    max
+                ),
+            ));
+        }
+        let fold = Fold { axis, index };
+        folded.fold_quietly(fold);
+        folds.push(fold)
+    }
+
+    if folds.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected at least one fold",
+        ));
+    }
+
+    let matrix = Matrix { dots };
+
+    Ok((matrix, folds))
+}
+
+/// Counts the dots that are left after the first fold
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let (mut matrix, mut folds) = parse_input(input)?;
+
+    let fold = folds.remove(0);
+    matrix.apply_fold(fold);
+
+    Ok(matrix.dots.len())
+}
+
+/// Draws the paper after all of the folds, which shows eight capital letters
+pub fn solve_part2(input: &str) -> Result<String, ParseError> {
+    let (mut matrix, folds) = parse_input(input)?;
+    for fold in folds {
+        matrix.apply_fold(fold);
+    }
+    Ok(matrix.render())
+}
+
+/// Draws the paper after all of the folds, with black dots on white
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    let (mut matrix, folds) = parse_input(input)?;
+    for fold in folds {
+        matrix.apply_fold(fold);
+    }
+    let cells = matrix
+        .paper()
+        .map(|&dot| if dot { Rgb::BLACK } else { Rgb::WHITE });
+    Ok(Image::new(cells))
+}
+
+/// Transparent Origami, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(Answer::Block(solve_part2(input)?))
+    }
+
+    fn render(&self, input: &str) -> Result<Image, SolveError> {
+        Ok(render(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13.part1(EXAMPLE).unwrap(), "17");
+    }
+
+    #[test]
+    fn part2_example() {
+        let expected = "\
+#####
+#...#
+#...#
+#...#
+#####";
+        assert_eq!(Day13.part2(EXAMPLE).unwrap(), expected);
+    }
+
+    #[test]
+    fn folds_have_to_be_halfway_across() {
+        let error = parse_input("10,0\n\nfold along x=2\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 14));
+        assert!(parse_input("10,0\n4,0\n\nfold along x=5\nfold along x=1\n").is_err());
+        assert!(parse_input("10,0\n4,0\n\nfold along x=5\nfold along x=2\n").is_ok());
+    }
+
+    #[test]
+    fn render_draws_the_dots() {
+        let image = Day13.render(EXAMPLE).unwrap();
+        let cells = image.cells();
+        assert_eq!((cells.width(), cells.height()), (5, 5));
+        assert_eq!(cells.iter().filter(|&&cell| cell == Rgb::BLACK).count(), 16);
+    }
+}
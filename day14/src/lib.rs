@@ -0,0 +1,191 @@
+//! Day 14: Extended Polymerization
+//!
+//! Grows a polymer by inserting elements between pairs, keeping count of pairs rather than the polymer itself.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Solution, SolveResult};
+use std::collections::HashMap;
+
+/// An element, written as a capital letter
+pub type Element = char;
+/// Two elements that are next to each other in the polymer
+pub type Pair = (Element, Element);
+
+/// The insertion rules, and how often each element and pair occurs in the polymer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polymizer {
+    formulas: HashMap<Pair, Element>,
+    elements: HashMap<Element, usize>,
+    pairs: HashMap<Pair, usize>,
+}
+
+impl Polymizer {
+    /// How often each element occurs in the polymer
+    pub fn elements(&self) -> &HashMap<Element, usize> {
+        &self.elements
+    }
+
+    /// How often each pair occurs in the polymer
+    pub fn pairs(&self) -> &HashMap<Pair, usize> {
+        &self.pairs
+    }
+
+    /// Inserts an element between every pair that has a rule
+    pub fn step(&mut self) {
+        for (pair, count) in self.pairs.clone() {
+            // Pairs without a rule stay as they are
+            let Some(&new_element) = self.formulas.get(&pair) else {
+                continue;
+            };
+            *self.elements.entry(new_element.to_owned()).or_insert(0) += count;
+            *self.pairs.entry(pair.to_owned()).or_insert(0) -= count;
+            *self.pairs.entry((pair.0, new_element)).or_insert(0) += count;
+            *self.pairs.entry((new_element, pair.1)).or_insert(0) += count;
+        }
+    }
+}
+
+fn parse_element(line: &InputLine, token: &str) -> Result<Element, ParseError> {
+    match token.chars().next() {
+        Some(c) if c.is_ascii_uppercase() => Ok(c),
+        Some(c) => Err(line.error(token, format!("expected an element, found `{}`", c))),
+        None => Err(line.error(token, "expected an element")),
+    }
+}
+
+/// Parses the polymer template, then a blank line and one rule per line, such as `CH -> B`
+pub fn parse_input(input: &str) -> Result<Polymizer, ParseError> {
+    let mut sections = parse::sections(input);
+
+    let header = sections
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "expected a polymer template"))?;
+    let template = &header[0];
+    for (column, c) in template.chars() {
+        if !c.is_ascii_uppercase() {
+            return Err(template.error_at(column, format!("expected an element, found `{}`", c)));
+        }
+    }
+    let initial_formula = String::from(template.text());
+    if let Some(line) = header.get(1) {
+        return Err(line.error_at(1, "expected a blank line after the template"));
+    }
+
+    let mut formulas = HashMap::new();
+    for line in sections.flatten() {
+        let (input, output) = line.split_once(" -> ")?;
+        if input.chars().count() != 2 {
+            return Err(line.error(input, "expected a pair of elements"));
+        }
+        if output.chars().count() != 1 {
+            return Err(line.error(output, "expected a single element"));
+        }
+        let input = (
+            parse_element(&line, input)?,
+            parse_element(&line, &input[1..])?,
+        );
+        let output = parse_element(&line, output)?;
+        formulas.insert(input, output);
+    }
+
+    let mut pairs = HashMap::new();
+    let mut elements = HashMap::new();
+
+    for i in 0..initial_formula.len() - 1 {
+        let mut chars = initial_formula.chars();
+        let element1 = chars.nth(i).unwrap();
+        let element2 = chars.next().unwrap();
+        let pair = (element1, element2);
+        *pairs.entry(pair).or_insert(0) += 1;
+        *elements.entry(element1).or_insert(0) += 1;
+    }
+
+    let element = initial_formula.chars().last().unwrap();
+    *elements.entry(element).or_insert(0) += 1;
+
+    Ok(Polymizer {
+        formulas,
+        elements,
+        pairs,
+    })
+}
+
+fn solve(input: &str, num_steps: u8) -> Result<usize, ParseError> {
+    let mut polymizer = parse_input(input)?;
+    for _ in 0..num_steps {
+        polymizer.step();
+    }
+
+    let max = *polymizer.elements.values().max().unwrap();
+    let min = *polymizer.elements.values().min().unwrap();
+    Ok(max - min)
+}
+
+/// The most common element's count minus the least common one's after 10 steps
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    solve(input, 10)
+}
+
+/// The most common element's count minus the least common one's after 40 steps
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    solve(input, 40)
+}
+
+/// Extended Polymerization, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day14.part1(EXAMPLE).unwrap(), "1588");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day14.part2(EXAMPLE).unwrap(), "2188189693529");
+    }
+}
@@ -0,0 +1,180 @@
+//! Day 15: Chiton
+//!
+//! Finds the path with the lowest total risk through a cave full of chitons.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Grid, Image, ParseError, Point, Rgb, Solution, SolveError, SolveResult};
+use aoc_graph::Graph;
+use tracing::debug;
+
+/// The risk level map, as a graph where moving onto a position costs its risk level
+#[derive(Debug, Clone)]
+pub struct Matrix {
+    graph: Graph<Point, usize>,
+    risk_levels: Grid<usize>,
+}
+
+/// Parses one row of risk levels from 1 to 9 per line
+pub fn parse_input(input: &str) -> Result<Matrix, ParseError> {
+    let risk_levels = Grid::parse_with(input, |c| {
+        c.to_digit(10)
+            .filter(|&risk_level| risk_level >= 1)
+            .map(|risk_level| risk_level as usize)
+    })?;
+    if risk_levels.is_empty() {
+        return Err(ParseError::end_of_input(input, "expected a risk level map"));
+    }
+    Ok(Matrix::new(risk_levels))
+}
+
+impl Matrix {
+    /// Panics if the map is empty
+    pub fn new(risk_levels: Grid<usize>) -> Matrix {
+        assert!(!risk_levels.is_empty(), "the risk level map is empty");
+        Matrix {
+            graph: Graph::from_grid(&risk_levels, |&risk_level| risk_level),
+            risk_levels,
+        }
+    }
+
+    /// The risk level of entering each position
+    pub fn risk_levels(&self) -> &Grid<usize> {
+        &self.risk_levels
+    }
+
+    /// The path with the lowest total risk from the top left to the bottom right corner,
+    /// and that total. The top left position is never entered, so its risk doesn't count.
+    pub fn lowest_risk_path(&self) -> (usize, Vec<Point>) {
+        let target =
+            Point::from_grid_index((self.risk_levels.height() - 1, self.risk_levels.width() - 1));
+        // Every step costs at least this much, so the heuristic never overestimates
+        let min_risk_level = *self.risk_levels.iter().min().unwrap();
+        let (total_risk, path) = self
+            .graph
+            .astar(
+                self.graph.id(&Point::ORIGIN).unwrap(),
+                self.graph.id(&target).unwrap(),
+                |id| self.graph.name(id).manhattan_distance(target) as usize * min_risk_level,
+            )
+            .expect("every position can be reached");
+        let path: Vec<Point> = path.into_iter().map(|id| *self.graph.name(id)).collect();
+        debug!(total_risk, steps = path.len() - 1, "path found");
+        (total_risk, path)
+    }
+
+    /// Repeats the map `size` times in both directions,
+    /// with the risk levels going up by 1 for each repeat and wrapping from 9 back to 1
+    pub fn expand(self, size: usize) -> Matrix {
+        let original_width = self.risk_levels.width();
+        let new_width = original_width * size;
+        let original_height = self.risk_levels.height();
+        let new_height = original_height * size;
+
+        let risk_levels = Grid::from_fn(new_width, new_height, |y, x| {
+            let original = self.risk_levels[(y % original_height, x % original_width)];
+            let risk_level = original + (x / original_width) + (y / original_height);
+            if risk_level >= 10 {
+                risk_level % 9
+            } else {
+                risk_level
+            }
+        });
+
+        Matrix::new(risk_levels)
+    }
+}
+
+/// The lowest total risk of any path through the map
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let matrix = parse_input(input)?;
+    Ok(matrix.lowest_risk_path().0)
+}
+
+/// The lowest total risk of any path through the map repeated five times in both directions
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let matrix = parse_input(input)?.expand(5);
+    Ok(matrix.lowest_risk_path().0)
+}
+
+/// Draws the risk levels from dark for 1 to light for 9,
+/// with the path with the lowest total risk in red on top
+pub fn render(input: &str) -> Result<Image, ParseError> {
+    let matrix = parse_input(input)?;
+    let mut cells = matrix
+        .risk_levels()
+        .map(|&risk_level| Rgb(20, 50, 20).mix(Rgb(225, 235, 200), (risk_level - 1) as f64 / 8.0));
+    for point in matrix.lowest_risk_path().1 {
+        cells[point] = Rgb::RED;
+    }
+    Ok(Image::new(cells))
+}
+
+/// Chiton, solved by [`solve_part1`] and [`solve_part2`]
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(&self, input: &str) -> SolveResult {
+        Ok(solve_part2(input)?.into())
+    }
+
+    fn render(&self, input: &str) -> Result<Image, SolveError> {
+        Ok(render(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day15.part1(EXAMPLE).unwrap(), "40");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day15.part2(EXAMPLE).unwrap(), "315");
+    }
+
+    #[test]
+    fn risk_levels_start_at_1() {
+        let error = parse_input("19\n10\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+    }
+
+    #[test]
+    fn render_draws_the_path_between_corners() {
+        let image = Day15.render(EXAMPLE).unwrap();
+        let cells = image.cells();
+        assert_eq!((cells.width(), cells.height()), (10, 10));
+        assert_eq!(cells[(0, 0)], Rgb::RED);
+        assert_eq!(cells[(9, 9)], Rgb::RED);
+        assert_ne!(cells[(0, 9)], Rgb::RED);
+    }
+}
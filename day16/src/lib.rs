@@ -0,0 +1,359 @@
+//! Day 16: Packet Decoder
+//!
+//! Decodes a hierarchy of packets from a hexadecimal transmission.
+
+#![warn(missing_docs)]
+
+use aoc_common::parse::{self, InputLine, ParseError};
+use aoc_common::{Solution, SolveResult};
+use std::ops::Range;
+use tracing::trace;
+
+fn convert<'a>(c: char) -> Option<&'a str> {
+    let bits = match c {
+        '0' => "0000",
+        '1' => "0001",
+        '2' => "0010",
+        '3' => "0011",
+        '4' => "0100",
+        '5' => "0101",
+        '6' => "0110",
+        '7' => "0111",
+        '8' => "1000",
+        '9' => "1001",
+        'A' => "1010",
+        'B' => "1011",
+        'C' => "1100",
+        'D' => "1101",
+        'E' => "1110",
+        'F' => "1111",
+        _ => return None,
+    };
+    Some(bits)
+}
+
+/// How deeply packets can be nested inside each other, which keeps decoding from running out of stack
+const MAX_DEPTH: usize = 64;
+
+/// The column of the hexadecimal digit holding the given bit of `binary`,
+/// which is what is left of the transmission on `line`
+fn column_of_bit(line: &InputLine, binary: &str, bit: usize) -> usize {
+    let offset = line.text().len() * 4 - binary.len() + bit.min(binary.len());
+    offset / 4 + 1
+}
+
+/// The given bits of `binary`, which is what is left of the transmission on `line`
+fn take_bits<'a>(
+    line: &InputLine,
+    binary: &'a str,
+    bits: Range<usize>,
+) -> Result<&'a str, ParseError> {
+    binary.get(bits.clone()).ok_or_else(|| {
+        line.error_at(
+            column_of_bit(line, binary, bits.start),
+            "packet ends unexpectedly",
+        )
+    })
+}
+
+fn binary_str_to_number(s: &str) -> usize {
+    let mut res = 0;
+    for c in s.chars() {
+        res <<= 1;
+        res |= c.to_digit(10).unwrap();
+    }
+    res as usize
+}
+
+/// How the length of an operator packet's sub-packets is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// If the length type ID is 0,
+    /// then the next 15 bits are a number that represents
+    /// the total length in bits of the sub-packets contained
+    /// by this packet.
+    LengthInBits = 0,
+    /// If the length type ID is 1, then the next 11 bits
+    /// are a number that represents the number of sub-packets
+    /// immediately contained by this packet.
+    NumberOfSubPackets = 1,
+}
+
+impl From<char> for LengthType {
+    fn from(s: char) -> Self {
+        match s {
+            '0' => LengthType::LengthInBits,
+            '1' => LengthType::NumberOfSubPackets,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// What a packet holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketType {
+    /// A single number, with a type ID of 4
+    LiteralValue,
+    /// Sub-packets, with the type ID that says which operation to apply to them
+    Operator(usize),
+}
+
+impl From<&str> for PacketType {
+    fn from(s: &str) -> Self {
+        let num = binary_str_to_number(s);
+        match num {
+            4 => PacketType::LiteralValue,
+            n => PacketType::Operator(n),
+        }
+    }
+}
+
+/// A packet, along with its sub-packets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    version: usize,
+    r#type: PacketType,
+    value: usize,
+    // The total number of bits, this packet takes up. May include sub-packets
+    packet_size_bits: usize,
+    sub_packets: Vec<Packet>,
+    length_type_id: Option<LengthType>,
+}
+
+impl Packet {
+    /// The version in the packet's header
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// What the packet holds
+    pub fn packet_type(&self) -> PacketType {
+        self.r#type
+    }
+
+    /// The number held by a literal value packet, 0 for operator packets
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// How the length of the sub-packets is given, for operator packets
+    pub fn length_type(&self) -> Option<LengthType> {
+        self.length_type_id
+    }
+
+    /// The sub-packets of an operator packet, in order
+    pub fn sub_packets(&self) -> &[Packet] {
+        &self.sub_packets
+    }
+
+    fn total_size(&self) -> usize {
+        let mut sum = self.packet_size_bits;
+        for packet in self.sub_packets.iter() {
+            sum += packet.total_size();
+        }
+        sum
+    }
+
+    /// The sum of the versions of this packet and all the packets inside it
+    pub fn sum_versions(&self) -> usize {
+        let mut sum = self.version;
+        for packet in self.sub_packets.iter() {
+            sum += packet.sum_versions();
+        }
+        sum
+    }
+
+    /// Decodes the transmission of hexadecimal digits on `line`
+    pub fn new(line: &InputLine) -> Result<Packet, ParseError> {
+        let mut binary = String::with_capacity(line.text().len() * 4);
+        for (column, c) in line.chars() {
+            let bits = convert(c).ok_or_else(|| {
+                line.error_at(
+                    column,
+                    format!("expected a hexadecimal digit, found `{}`", c),
+                )
+            })?;
+            binary.push_str(bits);
+        }
+        Packet::new_inner(line, binary.as_str(), 0)
+    }
+
+    /// Decodes the packet at the start of `binary`, which is inside `depth` other packets
+    fn new_inner(line: &InputLine, binary: &str, depth: usize) -> Result<Packet, ParseError> {
+        if depth > MAX_DEPTH {
+            return Err(line.error_at(
+                column_of_bit(line, binary, 0),
+                format!("packets are nested more than {} deep", MAX_DEPTH),
+            ));
+        }
+        let version = binary_str_to_number(take_bits(line, binary, 0..3)?);
+        let packet_type = PacketType::from(take_bits(line, binary, 3..6)?);
+
+        let packet = match packet_type {
+            PacketType::LiteralValue => Packet::create_literal_packet(line, version, binary),
+            PacketType::Operator(type_id) => {
+                Packet::create_operator_packet(line, version, type_id, binary, depth)
+            }
+        }?;
+        trace!(
+            version,
+            packet_type = ?packet.r#type,
+            value = packet.value,
+            sub_packets = packet.sub_packets.len(),
+            bits = packet.packet_size_bits,
+            "packet decoded"
+        );
+        Ok(packet)
+    }
+
+    fn create_literal_packet(
+        line: &InputLine,
+        version: usize,
+        binary: &str,
+    ) -> Result<Packet, ParseError> {
+        // First 3 bits are the version, the next 3 bits are the packet type (literal)
+        let mut packet_size_bits = 6;
+        let mut i = 6;
+        let mut value = 0;
+
+        loop {
+            let slice = take_bits(line, binary, i..i + 5)?;
+            value |= binary_str_to_number(&slice[1..]);
+
+            packet_size_bits += slice.len();
+            i += 5;
+
+            if slice.starts_with('0') {
+                // Last Group
+                break;
+            }
+            value <<= 4;
+        }
+
+        Ok(Packet {
+            version,
+            r#type: PacketType::LiteralValue,
+            value,
+            packet_size_bits,
+            sub_packets: Vec::new(),
+            length_type_id: None,
+        })
+    }
+
+    fn create_operator_packet(
+        line: &InputLine,
+        version: usize,
+        type_id: usize,
+        binary: &str,
+        depth: usize,
+    ) -> Result<Packet, ParseError> {
+        // First 3 bits are the version, the next 3 bits are the packet type (operator)
+        let mut packet_size_bits = 6;
+
+        let length_type_id =
+            LengthType::from(take_bits(line, binary, 6..7)?.chars().next().unwrap());
+        // 1 bit for the length type id
+        packet_size_bits += 1;
+
+        let mut sub_packets = Vec::new();
+
+        match length_type_id {
+            LengthType::LengthInBits => {
+                packet_size_bits += 15;
+                let number_of_bits = binary_str_to_number(take_bits(line, binary, 7..22)?);
+                let mut i = 22;
+                let mut count = 0;
+                while count < number_of_bits {
+                    let slice = &binary[i..];
+                    let packet = Packet::new_inner(line, slice, depth + 1)?;
+                    i += packet.total_size();
+                    count += packet.total_size();
+                    sub_packets.push(packet);
+                }
+            }
+            LengthType::NumberOfSubPackets => {
+                packet_size_bits += 11;
+                let number_of_subpackets = binary_str_to_number(take_bits(line, binary, 7..18)?);
+                let mut i = 18;
+                for _ in 0..number_of_subpackets {
+                    let slice = &binary[i..];
+                    let packet = Packet::new_inner(line, slice, depth + 1)?;
+                    i += packet.total_size();
+                    sub_packets.push(packet);
+                }
+            }
+        };
+
+        Ok(Packet {
+            version,
+            r#type: PacketType::Operator(type_id),
+            value: 0,
+            packet_size_bits,
+            sub_packets,
+            length_type_id: Some(length_type_id),
+        })
+    }
+}
+
+/// Parses the outermost packet of the transmission on the first line
+pub fn parse_input(input: &str) -> Result<Packet, ParseError> {
+    let line = parse::lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a transmission"))?;
+    Packet::new(&line)
+}
+
+/// Sums the versions of every packet in the transmission
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let packet = parse_input(input)?;
+    Ok(packet.sum_versions())
+}
+
+/// Packet Decoder, solved by [`solve_part1`]
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        parse_input(input).map(drop)
+    }
+
+    fn part1(&self, input: &str) -> SolveResult {
+        Ok(solve_part1(input)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day16.part1("8A004A801A8002F478").unwrap(), "16");
+        assert_eq!(Day16.part1("620080001611562C8802118E34").unwrap(), "12");
+        assert_eq!(Day16.part1("C0015000016115A2E0802F182340").unwrap(), "23");
+        assert_eq!(Day16.part1("A0016C880162017C3686B18A3D4780").unwrap(), "31");
+    }
+
+    #[test]
+    fn deeply_nested_packets_are_an_error() {
+        // Operator packets with one sub-packet each, around a single literal value
+        let mut bits = "000000100000000001".repeat(1000) + "00010000001";
+        while !bits.len().is_multiple_of(4) {
+            bits.push('0');
+        }
+        let hex: String = bits
+            .as_bytes()
+            .chunks(4)
+            .map(|digit| {
+                let digit = u32::from_str_radix(std::str::from_utf8(digit).unwrap(), 2).unwrap();
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect();
+        let error = parse_input(&hex).unwrap_err();
+        assert_eq!(error.message(), "packets are nested more than 64 deep");
+    }
+}